@@ -5,9 +5,7 @@
 //! DFLOW_API_KEY=your-api-key cargo run --example get_events
 //! ```
 
-use dflow_api_client::prediction::{
-    DflowPredictionApiClient, GetEventsParams, MarketStatus, SortField,
-};
+use dflow_api_client::prediction::DflowPredictionApiClient;
 
 #[tokio::main]
 async fn main() {
@@ -1,8 +1,17 @@
 //! Common utilities and types shared across DFlow API clients.
 
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use futures_util::stream::{self, BoxStream, StreamExt};
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
 use reqwest::{
-    Client,
-    header::{HeaderMap, HeaderValue},
+    Client, RequestBuilder, StatusCode,
+    header::{HeaderMap, HeaderName, HeaderValue, RETRY_AFTER},
 };
 use thiserror::Error;
 
@@ -27,12 +36,45 @@ pub enum DflowApiError {
     RequestFailed(#[from] reqwest::Error),
 
     /// API returned an error response
+    ///
+    /// Carries the response headers (e.g. rate limit counters or a request
+    /// ID for support) and the raw body alongside the extracted message.
     #[error("API error (status {status_code}): {message}")]
-    ApiError { status_code: u16, message: String },
+    ApiError {
+        status_code: u16,
+        message: String,
+        /// Response headers, keyed by lowercase name
+        headers: HashMap<String, String>,
+        /// Raw response body
+        body: String,
+    },
+
+    /// A successful response body didn't match the expected shape
+    ///
+    /// Distinguishes "the API changed shape" (a serde error about a field)
+    /// from "wrong route" (a body that isn't the expected kind of JSON at
+    /// all).
+    #[error("Failed to parse response from {endpoint}: {source}")]
+    ParseError {
+        /// Path of the request
+        endpoint: String,
+        /// The deserialization error
+        source: serde_json::Error,
+        /// Start of the response body
+        body_snippet: String,
+    },
 
-    /// Failed to parse response body
-    #[error("Failed to parse response: {0}")]
-    ParseError(String),
+    /// A value in a response or argument couldn't be decoded (e.g. a price
+    /// string or a transaction)
+    #[error("Invalid data: {0}")]
+    InvalidData(String),
+
+    /// The response body ended before its declared `Content-Length`
+    ///
+    /// Usually caused by the connection dropping mid-body, so the request
+    /// can be retried.
+    #[error("Incomplete response: expected {expected} bytes, got {got}")]
+    IncompleteResponse { expected: usize, got: usize },
 
     /// Invalid parameter provided
     #[error("Invalid parameter: {0}")]
@@ -53,38 +95,402 @@ pub enum DflowApiError {
     /// No route found for the swap (Swap API specific)
     #[error("No route found: {0}")]
     NoRouteFound(String),
+
+    /// The quote is stale and must be refreshed (Swap API specific). Only
+    /// returned for a 400 whose error code or message is a known stale-quote
+    /// error
+    #[error("Stale quote: {0}")]
+    StaleQuote(String),
+
+    /// The swap transaction doesn't match the quote (Swap API specific)
+    #[error("Transaction verification failed: {0}")]
+    VerificationFailed(String),
+
+    /// The swap transaction failed simulation (Swap API specific)
+    #[error("Swap simulation failed: {0}")]
+    SimulationFailed(String),
+
+    /// A Solana RPC node rejected a call, as opposed to the DFlow API
+    #[error("Solana RPC {method} failed: {message}")]
+    Rpc {
+        /// JSON-RPC method
+        method: String,
+        /// Error reported by the node
+        message: String,
+    },
+
+    /// A transaction was sent but its outcome is unknown, so it may still
+    /// land. Check the signature before sending the swap again.
+    #[error("Transaction {signature} sent but not confirmed: {reason}")]
+    Unconfirmed {
+        /// Signature of the sent transaction (base58)
+        signature: String,
+        /// Why the outcome is unknown
+        reason: String,
+    },
 }
 
 impl DflowApiError {
-    /// Create an API error from status code and response body
-    pub fn from_response(status_code: u16, body: &str) -> Self {
+    /// Create an API error from status code, response headers and body
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dflow_api_client::DflowApiError;
+    /// use reqwest::header::{HeaderMap, HeaderValue};
+    ///
+    /// let mut headers = HeaderMap::new();
+    /// headers.insert("x-request-id", HeaderValue::from_static("req-42"));
+    ///
+    /// let body = r#"{"message": "upstream unavailable"}"#;
+    /// let error = DflowApiError::from_response(503, &headers, body);
+    ///
+    /// let DflowApiError::ApiError {
+    ///     message, headers, ..
+    /// } = error
+    /// else {
+    ///     panic!("expected an API error");
+    /// };
+    /// assert_eq!(message, "upstream unavailable");
+    /// assert_eq!(headers["x-request-id"], "req-42");
+    /// ```
+    pub fn from_response(
+        status_code: u16,
+        headers: &HeaderMap,
+        body: &str,
+    ) -> Self {
         match status_code {
             401 => DflowApiError::Unauthorized,
             404 => DflowApiError::NotFound(body.to_string()),
             429 => DflowApiError::RateLimited,
             _ => {
-                let message = serde_json::from_str::<ApiErrorResponse>(body)
-                    .ok()
+                let response =
+                    serde_json::from_str::<ApiErrorResponse>(body).ok();
+                let stale = response.as_ref().is_some_and(|e| {
+                    [&e.error, &e.message]
+                        .into_iter()
+                        .flatten()
+                        .any(|text| is_stale_quote_message(text))
+                });
+                let message = response
                     .and_then(|e| e.message.or(e.error))
                     .unwrap_or_else(|| body.to_string());
 
+                if status_code == 400 && stale {
+                    return DflowApiError::StaleQuote(message);
+                }
+
                 DflowApiError::ApiError {
                     status_code,
                     message,
+                    headers: header_map(headers),
+                    body: body.to_string(),
                 }
             }
         }
     }
+
+    /// Whether the error is transient, so repeating an idempotent request
+    /// (a GET) may succeed.
+    ///
+    /// True for timeouts, connection failures, rate limiting (429), server
+    /// errors (5xx) and incomplete responses. False for client errors (4xx)
+    /// and errors that repeating the same request can't fix, such as parse
+    /// errors or invalid parameters. `RetryPolicy` retries exactly these
+    /// errors for GET requests.
+    ///
+    /// A timeout or a 5xx after a POST was sent doesn't mean the server
+    /// didn't act on it. Use [`is_safe_to_resend`](Self::is_safe_to_resend)
+    /// for non-idempotent requests such as `submit_intent`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dflow_api_client::DflowApiError;
+    ///
+    /// assert!(DflowApiError::RateLimited.is_retryable());
+    /// assert!(!DflowApiError::Unauthorized.is_retryable());
+    /// ```
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            DflowApiError::RequestFailed(e) => {
+                e.is_timeout() || e.is_connect() || e.is_body()
+            }
+            DflowApiError::ApiError { status_code, .. } => *status_code >= 500,
+            DflowApiError::RateLimited
+            | DflowApiError::IncompleteResponse { .. } => true,
+            DflowApiError::ParseError { .. }
+            | DflowApiError::InvalidData(_)
+            | DflowApiError::InvalidParameter(_)
+            | DflowApiError::NotFound(_)
+            | DflowApiError::Unauthorized
+            | DflowApiError::NoRouteFound(_)
+            | DflowApiError::StaleQuote(_)
+            | DflowApiError::VerificationFailed(_)
+            | DflowApiError::SimulationFailed(_)
+            | DflowApiError::Rpc { .. }
+            | DflowApiError::Unconfirmed { .. } => false,
+        }
+    }
+
+    /// Whether a non-idempotent request (a POST) can safely be sent again.
+    ///
+    /// Only true for `RateLimited`: a 429 means the server rejected the
+    /// request without processing it. `RetryPolicy` retries exactly these
+    /// errors for POST requests.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dflow_api_client::DflowApiError;
+    ///
+    /// assert!(DflowApiError::RateLimited.is_safe_to_resend());
+    /// assert!(
+    ///     !DflowApiError::IncompleteResponse { expected: 10, got: 2 }
+    ///         .is_safe_to_resend()
+    /// );
+    /// ```
+    pub fn is_safe_to_resend(&self) -> bool {
+        matches!(self, DflowApiError::RateLimited)
+    }
+}
+
+/// Convert response headers into a map, joining repeated headers with `, `.
+///
+/// Values that aren't valid strings are skipped.
+pub(crate) fn header_map(headers: &HeaderMap) -> HashMap<String, String> {
+    let mut map: HashMap<String, String> = HashMap::new();
+    for (name, value) in headers {
+        let Ok(value) = value.to_str() else {
+            continue;
+        };
+        map.entry(name.as_str().to_string())
+            .and_modify(|existing| {
+                existing.push_str(", ");
+                existing.push_str(value);
+            })
+            .or_insert_with(|| value.to_string());
+    }
+    map
+}
+
+impl DflowApiError {
+    /// Append a summary of the request body to the error message.
+    ///
+    /// Only variants that carry a message are changed.
+    fn with_request_summary(self, summary: &str) -> Self {
+        let annotate =
+            |message: String| format!("{} (request: {})", message, summary);
+        match self {
+            DflowApiError::ApiError {
+                status_code,
+                message,
+                headers,
+                body,
+            } => DflowApiError::ApiError {
+                status_code,
+                message: annotate(message),
+                headers,
+                body,
+            },
+            DflowApiError::InvalidParameter(message) => {
+                DflowApiError::InvalidParameter(annotate(message))
+            }
+            DflowApiError::NotFound(message) => {
+                DflowApiError::NotFound(annotate(message))
+            }
+            DflowApiError::NoRouteFound(message) => {
+                DflowApiError::NoRouteFound(annotate(message))
+            }
+            DflowApiError::StaleQuote(message) => {
+                DflowApiError::StaleQuote(annotate(message))
+            }
+            other => other,
+        }
+    }
+}
+
+/// Maximum length of the body snippet in a `ParseError`
+const BODY_SNIPPET_LEN: usize = 512;
+
+impl DflowApiError {
+    /// Build a `ParseError` for a response body, truncating the body.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dflow_api_client::DflowApiError;
+    ///
+    /// let body = format!("{{\"events\": \"{}\"}}", "x".repeat(1000));
+    /// let source =
+    ///     serde_json::from_str::<Vec<u8>>(&body).unwrap_err();
+    /// let error = DflowApiError::parse_error("/api/v1/events", source, &body);
+    ///
+    /// let DflowApiError::ParseError {
+    ///     endpoint,
+    ///     body_snippet,
+    ///     ..
+    /// } = &error
+    /// else {
+    ///     panic!("expected a parse error");
+    /// };
+    /// assert_eq!(endpoint, "/api/v1/events");
+    /// assert!(body_snippet.starts_with("{\"events\""));
+    /// assert!(body_snippet.len() < body.len());
+    /// assert!(!error.is_retryable());
+    /// ```
+    pub fn parse_error(
+        endpoint: impl Into<String>,
+        source: serde_json::Error,
+        body: &str,
+    ) -> Self {
+        let mut end = body.len().min(BODY_SNIPPET_LEN);
+        while !body.is_char_boundary(end) {
+            end -= 1;
+        }
+        let mut body_snippet = body[..end].to_string();
+        if end < body.len() {
+            body_snippet.push_str("...");
+        }
+
+        DflowApiError::ParseError {
+            endpoint: endpoint.into(),
+            source,
+            body_snippet,
+        }
+    }
+}
+
+/// Error codes and messages the Swap API returns for a quote that went
+/// stale before the swap, compared case-insensitively
+const STALE_QUOTE_MESSAGES: [&str; 4] = [
+    "stale_quote",
+    "quote_expired",
+    "stale quote",
+    "quote expired",
+];
+
+/// Whether an error code or message says the quote went stale before the
+/// swap.
+///
+/// Only exact matches count, so unrelated errors that mention a slot or an
+/// expiry (e.g. an invalid `slippageBps`) stay `ApiError`s.
+fn is_stale_quote_message(message: &str) -> bool {
+    let message = message.trim();
+    STALE_QUOTE_MESSAGES
+        .iter()
+        .any(|known| message.eq_ignore_ascii_case(known))
 }
 
 pub type Result<T> = std::result::Result<T, DflowApiError>;
 
+/// Any error of this crate.
+///
+/// The prediction and swap clients share [`DflowApiError`]
+/// (`DflowPredictionApiError` and `DflowSwapApiError` are aliases of it), so
+/// this only adds the WebSocket client's errors. Useful as the error type
+/// of code using both the REST and WebSocket clients.
+///
+/// # Example
+///
+/// ```no_run
+/// use dflow_api_client::{DflowError, prediction::DflowPredictionApiClient};
+///
+/// async fn count_events(api_key: &str) -> Result<usize, DflowError> {
+///     let client = DflowPredictionApiClient::try_new(
+///         dflow_api_client::PREDICTION_DEFAULT_BASE_URL.to_string(),
+///         api_key.to_string(),
+///     )?;
+///     Ok(client.get_events(None).await?.events.len())
+/// }
+/// ```
+#[derive(Debug, Error)]
+pub enum DflowError {
+    /// Error of the prediction or swap REST clients
+    #[error(transparent)]
+    Api(#[from] DflowApiError),
+
+    /// Error of the WebSocket client
+    #[cfg(feature = "websocket")]
+    #[error(transparent)]
+    Ws(#[from] crate::prediction::websocket::DflowWsError),
+}
+
+impl DflowError {
+    /// Whether repeating the operation may succeed.
+    ///
+    /// See [`DflowApiError::is_retryable`] and, for WebSocket errors,
+    /// `DflowWsError::is_transient`.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            DflowError::Api(error) => error.is_retryable(),
+            #[cfg(feature = "websocket")]
+            DflowError::Ws(error) => error.is_transient(),
+        }
+    }
+}
+
+/// Extension methods for DFlow API results.
+///
+/// Applies to both the prediction and swap clients' `Result` types.
+pub trait ResultExt<T> {
+    /// Convert a `NotFound` error into `Ok(None)`.
+    ///
+    /// Successful results become `Ok(Some(_))` and all other errors are
+    /// passed through unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dflow_api_client::{CommonResult, DflowApiError, ResultExt};
+    ///
+    /// let found: CommonResult<u32> = Ok(1);
+    /// assert_eq!(found.not_found_as_none().unwrap(), Some(1));
+    ///
+    /// let missing: CommonResult<u32> =
+    ///     Err(DflowApiError::NotFound("EVENT".to_string()));
+    /// assert_eq!(missing.not_found_as_none().unwrap(), None);
+    ///
+    /// let failed: CommonResult<u32> = Err(DflowApiError::Unauthorized);
+    /// assert!(matches!(
+    ///     failed.not_found_as_none(),
+    ///     Err(DflowApiError::Unauthorized)
+    /// ));
+    /// ```
+    fn not_found_as_none(self) -> Result<Option<T>>;
+}
+
+impl<T> ResultExt<T> for Result<T> {
+    fn not_found_as_none(self) -> Result<Option<T>> {
+        match self {
+            Ok(value) => Ok(Some(value)),
+            Err(DflowApiError::NotFound(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
 // =========================================================================
 // HTTP Utilities
 // =========================================================================
 
+/// Characters percent-encoded in query keys and values: everything except
+/// the RFC 3986 unreserved characters.
+const QUERY_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
 /// Build query string from optional parameters.
 ///
+/// Keys and values are percent-encoded, so values may contain spaces, `&`,
+/// `=` and non-ASCII characters.
+///
+/// If the same key appears more than once, the last value that is set wins
+/// and the key keeps the position of its first occurrence. Unset (`None`)
+/// values never override a value set earlier.
+///
 /// # Arguments
 ///
 /// * `params` - Slice of key-value pairs where values are optional
@@ -92,21 +498,735 @@ pub type Result<T> = std::result::Result<T, DflowApiError>;
 /// # Returns
 ///
 /// A query string starting with `?` if there are any parameters, or empty string.
+///
+/// # Example
+///
+/// ```
+/// use dflow_api_client::build_query_string;
+///
+/// let query = build_query_string(&[
+///     ("limit", Some("10".to_string())),
+///     ("cursor", Some("20".to_string())),
+///     // An extra override for a key that is already set
+///     ("cursor", Some("40".to_string())),
+///     ("limit", None),
+/// ]);
+///
+/// assert_eq!(query, "?limit=10&cursor=40");
+///
+/// // Search queries are escaped
+/// let query = build_query_string(&[
+///     ("q", Some("US election & more".to_string())),
+///     ("limit", Some("5".to_string())),
+/// ]);
+///
+/// assert_eq!(query, "?q=US%20election%20%26%20more&limit=5");
+/// ```
 pub fn build_query_string(params: &[(&str, Option<String>)]) -> String {
-    let query_parts: Vec<String> = params
-        .iter()
-        .filter_map(|(key, value)| {
-            value.as_ref().map(|v| format!("{}={}", key, v))
-        })
-        .collect();
+    let mut query_parts: Vec<(&str, &str)> = Vec::new();
+    for (key, value) in params {
+        let Some(value) = value else {
+            continue;
+        };
+
+        match query_parts.iter_mut().find(|(k, _)| k == key) {
+            Some(part) => part.1 = value,
+            None => query_parts.push((key, value)),
+        }
+    }
 
     if query_parts.is_empty() {
         String::new()
     } else {
+        let query_parts: Vec<String> = query_parts
+            .iter()
+            .map(|(key, value)| {
+                format!(
+                    "{}={}",
+                    utf8_percent_encode(key, QUERY_ENCODE_SET),
+                    utf8_percent_encode(value, QUERY_ENCODE_SET)
+                )
+            })
+            .collect();
         format!("?{}", query_parts.join("&"))
     }
 }
 
+/// Request body fields that may be included in debug error messages.
+const DEBUG_SAFE_FIELDS: &[&str] = &["mint", "amount", "slippage", "ticker"];
+
+/// Request body fields that must never be included in error messages.
+const DEBUG_SENSITIVE_FIELDS: &[&str] =
+    &["signature", "key", "transaction", "secret", "address"];
+
+/// Build a redacted summary of a request body for error messages.
+///
+/// Only fields whose name mentions a mint, amount, slippage or ticker are
+/// kept (including inside nested objects); signatures, keys, transactions and
+/// addresses are always dropped.
+fn redacted_request_summary<B: serde::Serialize>(body: &B) -> Option<String> {
+    fn redact(value: serde_json::Value) -> Option<serde_json::Value> {
+        let serde_json::Value::Object(map) = value else {
+            return None;
+        };
+
+        let redacted: serde_json::Map<String, serde_json::Value> = map
+            .into_iter()
+            .filter_map(|(key, value)| {
+                let name = key.to_lowercase();
+                if DEBUG_SENSITIVE_FIELDS.iter().any(|f| name.contains(f)) {
+                    return None;
+                }
+                if value.is_object() {
+                    return redact(value).map(|value| (key, value));
+                }
+                DEBUG_SAFE_FIELDS
+                    .iter()
+                    .any(|f| name.contains(f))
+                    .then_some((key, value))
+            })
+            .collect();
+
+        (!redacted.is_empty()).then_some(serde_json::Value::Object(redacted))
+    }
+
+    let value = serde_json::to_value(body).ok()?;
+    redact(value).map(|value| value.to_string())
+}
+
+/// Default number of requests kept in flight by the batch helpers.
+pub const DEFAULT_CONCURRENCY: usize = 8;
+
+/// Run `fetch` for every input with at most `concurrency` requests in flight.
+///
+/// # Returns
+///
+/// Each input paired with its result, in input order.
+pub(crate) async fn fetch_concurrent<K, T, F, Fut>(
+    inputs: impl IntoIterator<Item = K>,
+    concurrency: usize,
+    fetch: F,
+) -> Vec<(K, Result<T>)>
+where
+    K: Clone,
+    F: Fn(K) -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    stream::iter(inputs)
+        .map(|input| {
+            let fut = fetch(input.clone());
+            async move { (input, fut.await) }
+        })
+        .buffered(concurrency.max(1))
+        .collect()
+        .await
+}
+
+// =========================================================================
+// Pagination
+// =========================================================================
+
+/// Pagination cursor of a list endpoint.
+///
+/// Events, markets and search page by offset (the number of items to skip),
+/// trades by the ID of the trade to continue from.
+///
+/// # Example
+///
+/// ```
+/// use dflow_api_client::Cursor;
+///
+/// let offset = Cursor::from(200);
+/// assert_eq!(offset.as_offset(), Some(200));
+/// assert_eq!(offset.to_string(), "200");
+///
+/// let token = Cursor::from("trade-123".to_string());
+/// assert_eq!(token.as_token(), Some("trade-123"));
+/// assert_eq!(token.as_offset(), None);
+/// ```
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(untagged)]
+pub enum Cursor {
+    /// Number of items to skip
+    Offset(i32),
+    /// Opaque position token (e.g. a trade ID)
+    Token(String),
+}
+
+impl Cursor {
+    /// The offset, if this is an offset cursor.
+    pub fn as_offset(&self) -> Option<i32> {
+        match self {
+            Cursor::Offset(offset) => Some(*offset),
+            Cursor::Token(_) => None,
+        }
+    }
+
+    /// The token, if this is a token cursor.
+    pub fn as_token(&self) -> Option<&str> {
+        match self {
+            Cursor::Offset(_) => None,
+            Cursor::Token(token) => Some(token),
+        }
+    }
+}
+
+impl From<i32> for Cursor {
+    fn from(offset: i32) -> Self {
+        Cursor::Offset(offset)
+    }
+}
+
+impl From<String> for Cursor {
+    fn from(token: String) -> Self {
+        Cursor::Token(token)
+    }
+}
+
+impl std::fmt::Display for Cursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Cursor::Offset(offset) => write!(f, "{}", offset),
+            Cursor::Token(token) => f.write_str(token),
+        }
+    }
+}
+
+/// A page of a paginated list endpoint.
+///
+/// Implemented by the list responses whatever their cursor type, so
+/// pagination loops can be written once for all of them.
+///
+/// # Example
+///
+/// ```
+/// use dflow_api_client::{
+///     Cursor, Paginated,
+///     prediction::{EventsResponse, TradesResponse},
+/// };
+///
+/// fn has_more(page: &impl Paginated) -> bool {
+///     page.next_cursor().is_some()
+/// }
+///
+/// let events: EventsResponse =
+///     serde_json::from_str(r#"{"events":[],"cursor":100}"#).unwrap();
+/// assert_eq!(events.next_cursor(), Some(Cursor::Offset(100)));
+///
+/// let trades: TradesResponse = serde_json::from_str(r#"{"trades":[]}"#).unwrap();
+/// assert!(!has_more(&trades));
+/// ```
+pub trait Paginated {
+    /// Type of the listed items
+    type Item;
+
+    /// Cursor of the next page, `None` on the last page.
+    fn next_cursor(&self) -> Option<Cursor>;
+
+    /// The items of this page.
+    fn items(self) -> Vec<Self::Item>;
+}
+
+/// Stream the items of every page of a paginated endpoint.
+///
+/// Calls `fetch` with `None` for the first page and then with each page's
+/// [`Paginated::next_cursor`], yielding the items one by one. The stream
+/// ends when a page has no cursor or no items. If a page fails, its error
+/// is yielded as the last item.
+///
+/// # Example
+///
+/// ```no_run
+/// use dflow_api_client::{
+///     follow_pages,
+///     prediction::{DflowPredictionApiClient, SearchParams},
+/// };
+/// use futures_util::StreamExt;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = DflowPredictionApiClient::with_default_url("api-key".to_string());
+///
+/// let mut events = follow_pages(|cursor| {
+///     let params = SearchParams {
+///         q: "election".to_string(),
+///         cursor: cursor.and_then(|cursor| cursor.as_offset()),
+///         ..Default::default()
+///     };
+///     client.search_events(params)
+/// });
+/// while let Some(event) = events.next().await {
+///     println!("{}", event?.ticker);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn follow_pages<'a, P, F, Fut>(fetch: F) -> BoxStream<'a, Result<P::Item>>
+where
+    P: Paginated + 'a,
+    P::Item: Send + 'a,
+    F: FnMut(Option<Cursor>) -> Fut + Send + 'a,
+    Fut: Future<Output = Result<P>> + Send + 'a,
+{
+    // `None` once the last page was fetched, else the cursor to fetch with
+    let start: Option<Option<Cursor>> = Some(None);
+
+    stream::unfold((fetch, start), |(mut fetch, next)| async move {
+        let cursor = next?;
+        let (items, next) = match fetch(cursor).await {
+            Ok(page) => {
+                let next = page.next_cursor();
+                let items = page.items();
+                let next = next.filter(|_| !items.is_empty()).map(Some);
+                (items.into_iter().map(Ok).collect(), next)
+            }
+            Err(e) => (vec![Err(e)], None),
+        };
+        Some((items, (fetch, next)))
+    })
+    .flat_map(|items: Vec<Result<P::Item>>| stream::iter(items))
+    .boxed()
+}
+
+// =========================================================================
+// Client Configuration
+// =========================================================================
+
+/// Default request timeout in seconds
+pub const DEFAULT_TIMEOUT_SECS: u64 = 30;
+
+/// HTTP configuration shared by the DFlow API clients.
+///
+/// Passed to `with_config` on either client. The default configuration uses
+/// a 30 second request timeout and reqwest's defaults otherwise.
+///
+/// # Operation Names
+///
+/// Per-operation timeouts are keyed by the name of the client method that
+/// issues the request:
+///
+/// - Prediction API: `get_event`, `get_events`,
+///   `get_event_forecast_percentile_history`,
+///   `get_event_forecast_percentile_history_by_mint`,
+///   `get_event_candlesticks`, `get_market`, `get_market_by_mint`,
+///   `get_markets`, `get_markets_batch`, `get_outcome_mints`,
+///   `filter_outcome_mints`, `get_market_candlesticks`,
+///   `get_market_candlesticks_by_mint`, `get_orderbook`,
+///   `get_orderbook_by_mint`, `get_trades`, `get_trades_by_mint`,
+///   `get_live_data`, `get_live_data_by_event`, `get_live_data_by_mint`,
+///   `get_series`, `get_series_by_ticker`, `get_tags_by_categories`,
+///   `get_filters_by_sports`, `search_events`
+/// - Swap API: `get_quote`, `create_swap`, `get_intent`, `submit_intent`
+///
+/// Helpers built on these methods (e.g. `trades_in_range` paging through
+/// `get_trades`) use the timeout of the underlying operation.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use dflow_api_client::ClientConfig;
+///
+/// let config = ClientConfig {
+///     connect_timeout: Some(Duration::from_secs(5)),
+///     ..Default::default()
+/// }
+/// .with_operation_timeout("get_quote", Duration::from_secs(2));
+///
+/// assert_eq!(config.timeout_for("get_quote"), Some(Duration::from_secs(2)));
+/// assert_eq!(config.timeout_for("get_events"), Some(Duration::from_secs(30)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ClientConfig {
+    /// Default timeout for every request (no timeout if `None`)
+    pub timeout: Option<Duration>,
+    /// Timeout for establishing a connection (no timeout if `None`)
+    pub connect_timeout: Option<Duration>,
+    /// How long idle pooled connections are kept (reqwest's default if
+    /// `None`)
+    pub pool_idle_timeout: Option<Duration>,
+    /// Timeouts overriding `timeout` for individual operations
+    pub operation_timeouts: HashMap<&'static str, Duration>,
+    /// Automatic retries of rate limited and failed requests (none if
+    /// `None`)
+    pub retry_policy: Option<RetryPolicy>,
+    /// Extra headers sent with every request, besides the API key
+    pub headers: HashMap<String, String>,
+    /// Proxy URL for all requests, e.g. `http://localhost:8080` (system
+    /// proxy settings if `None`)
+    pub proxy: Option<String>,
+    /// Client side limit on the request rate (unlimited if `None`)
+    pub rate_limiter: Option<RateLimiter>,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            timeout: Some(Duration::from_secs(DEFAULT_TIMEOUT_SECS)),
+            connect_timeout: None,
+            pool_idle_timeout: None,
+            operation_timeouts: HashMap::new(),
+            retry_policy: None,
+            headers: HashMap::new(),
+            proxy: None,
+            rate_limiter: None,
+        }
+    }
+}
+
+impl ClientConfig {
+    /// Override the timeout of a single operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - Operation name (see [Operation Names](#operation-names))
+    /// * `timeout` - Timeout for requests of this operation
+    pub fn with_operation_timeout(
+        mut self,
+        operation: &'static str,
+        timeout: Duration,
+    ) -> Self {
+        self.operation_timeouts.insert(operation, timeout);
+        self
+    }
+
+    /// Retry failed requests according to `policy`.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Throttle requests with `limiter`.
+    ///
+    /// Clients built from configs holding clones of the same limiter share
+    /// its budget.
+    pub fn with_rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// Send an extra header with every request.
+    pub fn with_header(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        self.headers.insert(name.into(), value.into());
+        self
+    }
+
+    /// Timeout for an operation, falling back to the global timeout.
+    pub fn timeout_for(&self, operation: &str) -> Option<Duration> {
+        self.operation_timeouts
+            .get(operation)
+            .copied()
+            .or(self.timeout)
+    }
+}
+
+/// Configuration shared by the prediction and swap clients.
+///
+/// Lets both HTTP clients be built from a single definition of timeouts,
+/// retries, headers and proxy via `with_shared_config`.
+///
+/// # Applicability
+///
+/// - `http`: applies to both clients. Per-operation timeouts are matched by
+///   operation name, so entries for swap operations are ignored by the
+///   prediction client and vice versa.
+/// - `prediction_base_url`: prediction client only.
+/// - `swap_base_url`: swap client only.
+/// - `debug_requests`: both clients.
+///
+/// The WebSocket client isn't configured from `DflowConfig`; its settings
+/// are passed when connecting.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use dflow_api_client::{
+///     ClientConfig, DflowConfig, DflowPredictionApiClient, DflowSwapApiClient,
+///     RetryPolicy,
+/// };
+///
+/// let config = DflowConfig {
+///     http: ClientConfig::default()
+///         .with_retry_policy(RetryPolicy::default())
+///         .with_header("x-app", "my-bot")
+///         .with_operation_timeout("get_quote", Duration::from_secs(2)),
+///     ..Default::default()
+/// };
+///
+/// let api_key = "api-key".to_string();
+/// let prediction =
+///     DflowPredictionApiClient::with_shared_config(api_key.clone(), &config)
+///         .unwrap();
+/// let swap = DflowSwapApiClient::with_shared_config(api_key, &config).unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DflowConfig {
+    /// HTTP settings used by both clients
+    pub http: ClientConfig,
+    /// Base URL of the prediction API (`prediction::DEFAULT_BASE_URL` if
+    /// `None`)
+    pub prediction_base_url: Option<String>,
+    /// Base URL of the swap API (`swap::DEFAULT_BASE_URL` if `None`)
+    pub swap_base_url: Option<String>,
+    /// Include a redacted summary of the request body in POST errors
+    pub debug_requests: bool,
+}
+
+/// Retry behavior for requests that fail with a transient error.
+///
+/// GET requests are retried on errors for which
+/// [`DflowApiError::is_retryable`] is true: timeouts, connection failures,
+/// `RateLimited` (429), `ApiError` with a 5xx status and
+/// `IncompleteResponse`. Other errors such as `Unauthorized` or `NotFound`
+/// fail immediately. The delay before retry `n` (starting at 0) is
+/// `base_delay * 2^n`, capped at `max_delay`, unless a 429 response carries a
+/// `Retry-After` header, which is used instead (also capped at `max_delay`).
+///
+/// POST requests (e.g. `create_swap`, `submit_intent`) are only retried on
+/// `RateLimited`. After any other failure the server may already have acted
+/// on the request, and sending it again could submit the same order twice.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use dflow_api_client::RetryPolicy;
+///
+/// let policy = RetryPolicy {
+///     jitter: false,
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(policy.delay(0), Duration::from_millis(200));
+/// assert_eq!(policy.delay(2), Duration::from_millis(800));
+/// assert_eq!(policy.delay(10), Duration::from_secs(10));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Maximum number of retries after the first attempt (default 3)
+    pub max_retries: u32,
+    /// Delay before the first retry (default 200ms)
+    pub base_delay: Duration,
+    /// Upper bound for the backoff and `Retry-After` delays (default 10s)
+    pub max_delay: Duration,
+    /// Randomize each delay between half and the full value, to avoid
+    /// clients retrying in lockstep (default true)
+    pub jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(10),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Backoff delay before retry `attempt` (starting at 0).
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let delay = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay);
+
+        if !self.jitter {
+            return delay;
+        }
+
+        // Cheap randomness is enough to spread out retries
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.subsec_nanos())
+            .unwrap_or_default();
+        let fraction = 0.5 + (nanos % 1000) as f64 / 2000.0;
+        delay.mul_f64(fraction)
+    }
+}
+
+/// Token bucket limiting the rate of outgoing requests.
+///
+/// The bucket holds up to `burst` tokens and refills at
+/// `requests_per_second`. Each request, including each retry, takes a token
+/// and waits for the bucket to refill if it's empty.
+///
+/// Clones share the bucket, so a limiter set on several clients (e.g. via
+/// [`DflowConfig`]) keeps the whole process within one budget.
+///
+/// # Example
+///
+/// ```
+/// use std::time::{Duration, Instant};
+///
+/// use dflow_api_client::{ClientConfig, RateLimiter};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let limiter = RateLimiter::new(20.0, 2).unwrap();
+/// let config = ClientConfig::default().with_rate_limiter(limiter.clone());
+///
+/// // The burst is available at once, the next request waits ~50ms
+/// let start = Instant::now();
+/// for _ in 0..3 {
+///     limiter.acquire().await;
+/// }
+/// assert!(start.elapsed() >= Duration::from_millis(40));
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    requests_per_second: f64,
+    burst: f64,
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+/// Shared state of a [`RateLimiter`].
+#[derive(Debug)]
+struct Bucket {
+    /// Available tokens, negative while requests are waiting for a refill
+    tokens: f64,
+    refilled_at: tokio::time::Instant,
+}
+
+impl RateLimiter {
+    /// Create a limiter allowing `requests_per_second` on average and bursts
+    /// of up to `burst` requests. The bucket starts full.
+    ///
+    /// # Returns
+    ///
+    /// The limiter, or `InvalidParameter` if `requests_per_second` isn't
+    /// positive and finite or `burst` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dflow_api_client::{DflowApiError, RateLimiter};
+    ///
+    /// assert!(RateLimiter::new(10.0, 20).is_ok());
+    /// assert!(matches!(
+    ///     RateLimiter::new(0.0, 20),
+    ///     Err(DflowApiError::InvalidParameter(_))
+    /// ));
+    /// ```
+    pub fn new(requests_per_second: f64, burst: u32) -> Result<Self> {
+        if !(requests_per_second.is_finite() && requests_per_second > 0.0) {
+            return Err(DflowApiError::InvalidParameter(format!(
+                "requests_per_second must be positive, got {}",
+                requests_per_second
+            )));
+        }
+        if burst == 0 {
+            return Err(DflowApiError::InvalidParameter(
+                "burst must be positive".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            requests_per_second,
+            burst: burst.into(),
+            bucket: Arc::new(Mutex::new(Bucket {
+                tokens: burst.into(),
+                refilled_at: tokio::time::Instant::now(),
+            })),
+        })
+    }
+
+    /// Average number of requests allowed per second.
+    pub fn requests_per_second(&self) -> f64 {
+        self.requests_per_second
+    }
+
+    /// Maximum number of requests allowed at once.
+    pub fn burst(&self) -> u32 {
+        self.burst as u32
+    }
+
+    /// Wait until a request may be sent.
+    ///
+    /// The token is reserved immediately, so a caller dropping the future
+    /// while it waits still uses up its share of the budget.
+    pub async fn acquire(&self) {
+        let wait = {
+            let mut bucket =
+                self.bucket.lock().unwrap_or_else(PoisonError::into_inner);
+            let now = tokio::time::Instant::now();
+            let elapsed = now.duration_since(bucket.refilled_at);
+            bucket.tokens = (bucket.tokens
+                + elapsed.as_secs_f64() * self.requests_per_second)
+                .min(self.burst);
+            bucket.refilled_at = now;
+            bucket.tokens -= 1.0;
+            (bucket.tokens < 0.0).then(|| {
+                Duration::from_secs_f64(
+                    -bucket.tokens / self.requests_per_second,
+                )
+            })
+        };
+
+        if let Some(wait) = wait {
+            tracing::debug!(?wait, "rate limited, waiting");
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+/// Options for a single request.
+///
+/// Headers are sent in addition to the client's default headers, replacing
+/// any default header of the same name.
+///
+/// # Example
+///
+/// ```
+/// use dflow_api_client::RequestOptions;
+///
+/// let options = RequestOptions::new().with_header("x-request-id", "swap-42");
+/// assert_eq!(options.headers["x-request-id"], "swap-42");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RequestOptions {
+    /// Extra headers for this request
+    pub headers: HashMap<String, String>,
+}
+
+impl RequestOptions {
+    /// Create options without extra headers, same as `Default`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a header to this request (e.g. a correlation ID or an
+    /// idempotency key).
+    pub fn with_header(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        self.headers.insert(name.into(), value.into());
+        self
+    }
+
+    /// Apply the options to a request.
+    fn apply(&self, request: RequestBuilder) -> Result<RequestBuilder> {
+        if self.headers.is_empty() {
+            return Ok(request);
+        }
+        Ok(request.headers(to_header_map(&self.headers)?))
+    }
+}
+
 /// Create an HTTP client with the given API key in the default headers.
 ///
 /// # Arguments
@@ -116,19 +1236,133 @@ pub fn build_query_string(params: &[(&str, Option<String>)]) -> String {
 /// # Returns
 ///
 /// A configured `reqwest::Client` with the API key header set.
+///
+/// # Panics
+///
+/// Panics if the API key isn't a valid header value. Use
+/// [`try_create_http_client`] to handle this as an error.
 pub fn create_http_client(api_key: &str) -> Client {
-    let mut default_headers = HeaderMap::new();
-    default_headers.insert(
-        "x-api-key",
-        HeaderValue::from_str(api_key).expect("Invalid API key"),
-    );
-
-    Client::builder()
-        .default_headers(default_headers)
-        .build()
+    create_http_client_with_config(api_key, &ClientConfig::default())
+}
+
+/// Create an HTTP client with the given API key and configuration.
+///
+/// # Arguments
+///
+/// * `api_key` - API key for authentication
+/// * `config` - HTTP configuration
+///
+/// # Returns
+///
+/// A configured `reqwest::Client` with the API key header set.
+///
+/// # Panics
+///
+/// Panics if the API key isn't a valid header value. Use
+/// [`try_create_http_client`] to handle this as an error.
+pub fn create_http_client_with_config(
+    api_key: &str,
+    config: &ClientConfig,
+) -> Client {
+    try_create_http_client(api_key, config)
         .expect("Failed to build HTTP client")
 }
 
+/// Create an HTTP client with the given API key and configuration, without
+/// panicking.
+///
+/// # Arguments
+///
+/// * `api_key` - API key for authentication
+/// * `config` - HTTP configuration
+///
+/// # Returns
+///
+/// A configured `reqwest::Client`, or `InvalidParameter` if the API key or
+/// one of the extra headers contains bytes that aren't allowed in a header
+/// (e.g. a trailing newline read from a file), or if the proxy URL is
+/// invalid.
+///
+/// # Example
+///
+/// ```
+/// use dflow_api_client::{ClientConfig, DflowApiError, try_create_http_client};
+///
+/// let config = ClientConfig::default();
+/// assert!(try_create_http_client("api-key", &config).is_ok());
+/// assert!(matches!(
+///     try_create_http_client("api-key\n", &config),
+///     Err(DflowApiError::InvalidParameter(_))
+/// ));
+/// ```
+pub fn try_create_http_client(
+    api_key: &str,
+    config: &ClientConfig,
+) -> Result<Client> {
+    let api_key = HeaderValue::from_str(api_key).map_err(|e| {
+        DflowApiError::InvalidParameter(format!("invalid API key: {}", e))
+    })?;
+
+    let mut default_headers = to_header_map(&config.headers)?;
+    default_headers.insert("x-api-key", api_key);
+
+    let mut builder = Client::builder().default_headers(default_headers);
+    if let Some(proxy) = &config.proxy {
+        let proxy = reqwest::Proxy::all(proxy).map_err(|e| {
+            DflowApiError::InvalidParameter(format!(
+                "invalid proxy URL {:?}: {}",
+                proxy, e
+            ))
+        })?;
+        builder = builder.proxy(proxy);
+    }
+    if let Some(timeout) = config.timeout {
+        builder = builder.timeout(timeout);
+    }
+    if let Some(timeout) = config.connect_timeout {
+        builder = builder.connect_timeout(timeout);
+    }
+    if let Some(timeout) = config.pool_idle_timeout {
+        builder = builder.pool_idle_timeout(timeout);
+    }
+
+    Ok(builder.build()?)
+}
+
+/// Build the runtime that drives the blocking clients.
+///
+/// # Panics
+///
+/// Panics if the runtime can't be created.
+#[cfg(feature = "blocking")]
+pub(crate) fn blocking_runtime() -> tokio::runtime::Runtime {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("Failed to build Tokio runtime")
+}
+
+/// Convert header names and values to a `HeaderMap`, validating them.
+fn to_header_map(headers: &HashMap<String, String>) -> Result<HeaderMap> {
+    let mut map = HeaderMap::new();
+    for (name, value) in headers {
+        let name = HeaderName::from_bytes(name.as_bytes()).map_err(|e| {
+            DflowApiError::InvalidParameter(format!(
+                "invalid header name {:?}: {}",
+                name, e
+            ))
+        })?;
+        let value = HeaderValue::from_str(value).map_err(|e| {
+            DflowApiError::InvalidParameter(format!(
+                "invalid value for header {}: {}",
+                name, e
+            ))
+        })?;
+        map.insert(name, value);
+    }
+    Ok(map)
+}
+
 /// Trait for common DFlow API client functionality.
 ///
 /// This trait provides the core HTTP methods (`get` and `post`) that are
@@ -141,24 +1375,42 @@ pub trait DflowHttpClient {
     /// Get the base URL
     fn base_url(&self) -> &str;
 
+    /// Whether to include a redacted summary of the request body in the
+    /// errors of failed POST requests (off by default).
+    fn debug_requests(&self) -> bool {
+        false
+    }
+
+    /// Timeout for requests of the given operation (none by default).
+    ///
+    /// Overrides the timeout configured on the HTTP client.
+    fn operation_timeout(&self, _operation: &str) -> Option<Duration> {
+        None
+    }
+
+    /// Policy for retrying transient failures (no retries by default).
+    fn retry_policy(&self) -> Option<&RetryPolicy> {
+        None
+    }
+
+    /// Limiter awaited before each request is sent (none by default).
+    fn rate_limiter(&self) -> Option<&RateLimiter> {
+        None
+    }
+
     /// Make a GET request to the API
     async fn get<T: serde::de::DeserializeOwned>(
         &self,
         endpoint: &str,
     ) -> Result<T> {
         let url = format!("{}{}", self.base_url(), endpoint);
-
-        let response = self.http_client().get(&url).send().await?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let body = response.text().await.unwrap_or_default();
-            return Err(DflowApiError::from_response(status.as_u16(), &body));
-        }
-
-        let body = response.text().await?;
-        serde_json::from_str(&body)
-            .map_err(|e| DflowApiError::ParseError(format!("{}: {}", e, body)))
+        send(
+            self.http_client().get(&url),
+            true,
+            self.retry_policy(),
+            self.rate_limiter(),
+        )
+        .await
     }
 
     /// Make a POST request to the API
@@ -168,17 +1420,364 @@ pub trait DflowHttpClient {
         body: &B,
     ) -> Result<T> {
         let url = format!("{}{}", self.base_url(), endpoint);
+        let request = self.http_client().post(&url).json(body);
+        send_with_summary(
+            request,
+            body,
+            self.debug_requests(),
+            self.retry_policy(),
+            self.rate_limiter(),
+        )
+        .await
+    }
+
+    /// Make a GET request for a named operation.
+    ///
+    /// Applies the operation's timeout, if any.
+    async fn get_op<T: serde::de::DeserializeOwned>(
+        &self,
+        operation: &str,
+        endpoint: &str,
+    ) -> Result<T> {
+        self.get_op_with_options(
+            operation,
+            endpoint,
+            &RequestOptions::default(),
+        )
+        .await
+    }
+
+    /// Make a POST request for a named operation.
+    ///
+    /// Applies the operation's timeout, if any.
+    async fn post_op<T: serde::de::DeserializeOwned, B: serde::Serialize>(
+        &self,
+        operation: &str,
+        endpoint: &str,
+        body: &B,
+    ) -> Result<T> {
+        self.post_op_with_options(
+            operation,
+            endpoint,
+            body,
+            &RequestOptions::default(),
+        )
+        .await
+    }
+
+    /// Make a GET request for a named operation with per-request options.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use dflow_api_client::{
+    ///     DflowHttpClient, RequestOptions, prediction::DflowPredictionApiClient,
+    /// };
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client =
+    ///     DflowPredictionApiClient::with_default_url("api-key".to_string());
+    ///
+    /// let options = RequestOptions::new().with_header("X-Request-Id", "ev-42");
+    /// let event: serde_json::Value = client
+    ///     .get_op_with_options("get_event", "/api/v1/event/TICKER", &options)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn get_op_with_options<T: serde::de::DeserializeOwned>(
+        &self,
+        operation: &str,
+        endpoint: &str,
+        options: &RequestOptions,
+    ) -> Result<T> {
+        let url = format!("{}{}", self.base_url(), endpoint);
+        let request = options.apply(self.http_client().get(&url))?;
+        let request = with_timeout(request, self.operation_timeout(operation));
+        send(request, true, self.retry_policy(), self.rate_limiter()).await
+    }
+
+    /// Make a POST request for a named operation with per-request options.
+    async fn post_op_with_options<T, B>(
+        &self,
+        operation: &str,
+        endpoint: &str,
+        body: &B,
+        options: &RequestOptions,
+    ) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+        B: serde::Serialize,
+    {
+        let url = format!("{}{}", self.base_url(), endpoint);
+        let request =
+            options.apply(self.http_client().post(&url).json(body))?;
+        let request = with_timeout(request, self.operation_timeout(operation));
+        send_with_summary(
+            request,
+            body,
+            self.debug_requests(),
+            self.retry_policy(),
+            self.rate_limiter(),
+        )
+        .await
+    }
+}
+
+/// Apply a per-request timeout if one is set.
+fn with_timeout(
+    request: RequestBuilder,
+    timeout: Option<Duration>,
+) -> RequestBuilder {
+    match timeout {
+        Some(timeout) => request.timeout(timeout),
+        None => request,
+    }
+}
+
+/// Send a request and parse the JSON response, retrying transient failures
+/// according to `retry_policy` and waiting for `rate_limiter` before each
+/// attempt.
+///
+/// Requests that aren't `idempotent` (POSTs) are only retried when rate
+/// limited: after any other failure the server may already have acted on
+/// them.
+async fn send<T: serde::de::DeserializeOwned>(
+    request: RequestBuilder,
+    idempotent: bool,
+    retry_policy: Option<&RetryPolicy>,
+    rate_limiter: Option<&RateLimiter>,
+) -> Result<T> {
+    let Some(policy) = retry_policy else {
+        return send_limited(request, rate_limiter)
+            .await
+            .map_err(|(error, _)| error);
+    };
+
+    let mut attempt = 0;
+    loop {
+        // Requests with a streaming body can't be cloned or retried
+        let Some(retry_request) = request.try_clone() else {
+            return send_limited(request, rate_limiter)
+                .await
+                .map_err(|(error, _)| error);
+        };
+
+        match send_limited(retry_request, rate_limiter).await {
+            Ok(value) => return Ok(value),
+            Err((error, retry_after)) => {
+                let retryable = if idempotent {
+                    error.is_retryable()
+                } else {
+                    error.is_safe_to_resend()
+                };
+                if attempt >= policy.max_retries || !retryable {
+                    return Err(error);
+                }
+                let delay = retry_after
+                    .map(|delay| delay.min(policy.max_delay))
+                    .unwrap_or_else(|| policy.delay(attempt));
+                tracing::debug!(
+                    attempt = attempt + 1,
+                    ?delay,
+                    error = %error,
+                    "retrying request"
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// Wait for the rate limiter, if any, then send a request once.
+async fn send_limited<T: serde::de::DeserializeOwned>(
+    request: RequestBuilder,
+    rate_limiter: Option<&RateLimiter>,
+) -> std::result::Result<T, (DflowApiError, Option<Duration>)> {
+    if let Some(limiter) = rate_limiter {
+        limiter.acquire().await;
+    }
+    send_once(request).await
+}
+
+/// Send a request once and parse the JSON response.
+///
+/// Errors come with the delay requested by a 429 response's `Retry-After`
+/// header, if any.
+async fn send_once<T: serde::de::DeserializeOwned>(
+    request: RequestBuilder,
+) -> std::result::Result<T, (DflowApiError, Option<Duration>)> {
+    let response = request.send().await.map_err(|e| (e.into(), None))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let retry_after = (status == StatusCode::TOO_MANY_REQUESTS)
+            .then(|| response.headers().get(RETRY_AFTER))
+            .flatten()
+            .and_then(|value| value.to_str().ok()?.trim().parse::<u64>().ok())
+            .map(Duration::from_secs);
+        let headers = response.headers().clone();
+        let body = response.text().await.unwrap_or_default();
+        return Err((
+            DflowApiError::from_response(status.as_u16(), &headers, &body),
+            retry_after,
+        ));
+    }
+
+    let endpoint = response.url().path().to_string();
+    let body = read_body(response).await.map_err(|e| (e, None))?;
+    serde_json::from_str(&body)
+        .map_err(|e| (DflowApiError::parse_error(endpoint, e, &body), None))
+}
+
+/// Read a response body, checking it against the declared `Content-Length`.
+///
+/// Returns `IncompleteResponse` if the body is shorter than declared, whether
+/// the connection was cut (a read error) or ended early.
+async fn read_body(mut response: reqwest::Response) -> Result<String> {
+    let expected = response.content_length().map(|len| len as usize);
+    let incomplete = |got: usize| {
+        expected
+            .filter(|&expected| got < expected)
+            .map(|expected| DflowApiError::IncompleteResponse { expected, got })
+    };
+
+    let mut body = Vec::new();
+    loop {
+        match response.chunk().await {
+            Ok(Some(chunk)) => body.extend_from_slice(&chunk),
+            Ok(None) => break,
+            Err(e) => return Err(incomplete(body.len()).unwrap_or(e.into())),
+        }
+    }
+
+    if let Some(error) = incomplete(body.len()) {
+        return Err(error);
+    }
+    Ok(String::from_utf8_lossy(&body).into_owned())
+}
+
+/// Send a request with a JSON body, optionally annotating failures with a
+/// redacted summary of the body.
+async fn send_with_summary<T, B>(
+    request: RequestBuilder,
+    body: &B,
+    debug_requests: bool,
+    retry_policy: Option<&RetryPolicy>,
+    rate_limiter: Option<&RateLimiter>,
+) -> Result<T>
+where
+    T: serde::de::DeserializeOwned,
+    B: serde::Serialize,
+{
+    send(request, false, retry_policy, rate_limiter)
+        .await
+        .map_err(|error| {
+            match debug_requests
+                .then(|| redacted_request_summary(body))
+                .flatten()
+            {
+                Some(summary) => error.with_request_summary(&summary),
+                None => error,
+            }
+        })
+}
 
-        let response = self.http_client().post(&url).json(body).send().await?;
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        let status = response.status();
-        if !status.is_success() {
-            let body = response.text().await.unwrap_or_default();
-            return Err(DflowApiError::from_response(status.as_u16(), &body));
+    fn api_error(status_code: u16) -> DflowApiError {
+        DflowApiError::ApiError {
+            status_code,
+            message: String::new(),
+            headers: HashMap::new(),
+            body: String::new(),
         }
+    }
 
-        let body = response.text().await?;
-        serde_json::from_str(&body)
-            .map_err(|e| DflowApiError::ParseError(format!("{}: {}", e, body)))
+    #[test]
+    fn transient_errors_are_retryable() {
+        assert!(DflowApiError::RateLimited.is_retryable());
+        assert!(api_error(500).is_retryable());
+        assert!(api_error(503).is_retryable());
+        assert!(
+            DflowApiError::IncompleteResponse {
+                expected: 10,
+                got: 2
+            }
+            .is_retryable()
+        );
+    }
+
+    #[test]
+    fn permanent_errors_are_not_retryable() {
+        for error in [
+            api_error(400),
+            api_error(422),
+            DflowApiError::Unauthorized,
+            DflowApiError::NotFound(String::new()),
+            DflowApiError::InvalidData(String::new()),
+            DflowApiError::InvalidParameter(String::new()),
+            DflowApiError::NoRouteFound(String::new()),
+            DflowApiError::StaleQuote(String::new()),
+            DflowApiError::VerificationFailed(String::new()),
+            DflowApiError::SimulationFailed(String::new()),
+        ] {
+            assert!(!error.is_retryable(), "{:?}", error);
+        }
+    }
+
+    #[test]
+    fn only_rate_limited_posts_are_resent() {
+        assert!(DflowApiError::RateLimited.is_safe_to_resend());
+        assert!(!api_error(503).is_safe_to_resend());
+        assert!(
+            !DflowApiError::IncompleteResponse {
+                expected: 10,
+                got: 2
+            }
+            .is_safe_to_resend()
+        );
+    }
+
+    #[test]
+    fn stale_quote_requires_exact_message() {
+        let error = |body: &str| {
+            DflowApiError::from_response(400, &HeaderMap::new(), body)
+        };
+
+        assert!(matches!(
+            error(r#"{"error": "STALE_QUOTE", "message": "try again"}"#),
+            DflowApiError::StaleQuote(message) if message == "try again"
+        ));
+        assert!(matches!(
+            error(r#"{"message": "Quote expired"}"#),
+            DflowApiError::StaleQuote(_)
+        ));
+        assert!(matches!(
+            error(r#"{"message": "slippageBps exceeds max slot drift"}"#),
+            DflowApiError::ApiError { .. }
+        ));
+        assert!(matches!(
+            DflowApiError::from_response(
+                500,
+                &HeaderMap::new(),
+                r#"{"message": "stale quote"}"#
+            ),
+            DflowApiError::ApiError { .. }
+        ));
+    }
+
+    #[test]
+    fn rate_limiter_rejects_invalid_settings() {
+        assert!(RateLimiter::new(10.0, 20).is_ok());
+        for (rate, burst) in [(0.0, 1), (-1.0, 1), (f64::NAN, 1), (1.0, 0)] {
+            assert!(matches!(
+                RateLimiter::new(rate, burst),
+                Err(DflowApiError::InvalidParameter(_))
+            ));
+        }
     }
 }
@@ -69,15 +69,19 @@ pub mod swap;
 
 // Re-export common types at the crate level for convenience
 pub use common::{
-    ApiErrorResponse, DflowApiError, DflowHttpClient, Result as CommonResult,
-    build_query_string, create_http_client,
+    ApiErrorResponse, ClientConfig, Cursor, DEFAULT_TIMEOUT_SECS,
+    DflowApiError, DflowConfig, DflowError, DflowHttpClient, Paginated,
+    RateLimiter, RequestOptions, Result as CommonResult, ResultExt,
+    RetryPolicy, build_query_string, create_http_client,
+    create_http_client_with_config, follow_pages, try_create_http_client,
 };
 // Re-export WebSocket types when the feature is enabled
 #[cfg(feature = "websocket")]
 pub use prediction::websocket::{
-    Channel, DEFAULT_WS_URL, DflowPredictionWsClient, DflowWsError,
-    OrderbookUpdate, PriceUpdate, SubscribeMessage, TradeUpdate, WsMessage,
-    WsResult,
+    Channel, ConnectionStatus, DEFAULT_WS_URL, DflowPredictionWsClient,
+    DflowWsError, OrderbookUpdate, PriceUpdate, Sequenced, SubscribeMessage,
+    SubscriptionAck, SubscriptionScope, SubscriptionState, TradeUpdate,
+    WsConfig, WsDebugState, WsMessage, WsResult,
 };
 pub use prediction::{
     DEFAULT_BASE_URL as PREDICTION_DEFAULT_BASE_URL, DflowPredictionApiClient,
@@ -85,5 +89,6 @@ pub use prediction::{
 };
 pub use swap::{
     DEFAULT_BASE_URL as SWAP_DEFAULT_BASE_URL, DflowSwapApiClient,
-    DflowSwapApiError, Result as SwapResult,
+    DflowSwapApiError, Result as SwapResult, USDC_MINT, USDT_MINT,
+    WRAPPED_SOL_MINT,
 };
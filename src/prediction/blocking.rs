@@ -0,0 +1,467 @@
+//! Blocking client for the DFlow Prediction Market Metadata API.
+//!
+//! Only available with the `blocking` feature enabled. Wraps the async
+//! client and drives it on an internal single-threaded Tokio runtime, so no
+//! runtime is needed by the caller.
+//!
+//! The methods must not be called from within an async runtime, as blocking
+//! there panics. The async polling streams (`events_stream`,
+//! `markets_stream`, `trades_stream`, `markets_changes_stream`) have no
+//! blocking counterpart; use `get_all_markets`, `trades_since` or page
+//! through `get_events` instead.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use dflow_api_client::prediction::blocking::DflowPredictionApiClient;
+//!
+//! let client = DflowPredictionApiClient::with_default_url("api-key".to_string());
+//!
+//! let events = client.get_events(None).unwrap();
+//! println!("Found {} events", events.events.len());
+//! ```
+
+use std::{collections::HashMap, sync::Arc};
+
+use tokio::runtime::Runtime;
+
+use super::{DflowPredictionApiClient as AsyncClient, Result, types::*};
+use crate::common::{ClientConfig, DflowConfig, blocking_runtime};
+
+/// Blocking client for the DFlow Prediction Market Metadata API.
+///
+/// Mirrors [`AsyncClient`] method for method. Clones share the connection
+/// pool and runtime.
+///
+/// ```
+/// use dflow_api_client::prediction::blocking::DflowPredictionApiClient;
+///
+/// // Nothing listens on port 1, so the request fails without a runtime
+/// // on the caller's side
+/// let client = DflowPredictionApiClient::new(
+///     "http://127.0.0.1:1".to_string(),
+///     "api-key".to_string(),
+/// );
+/// let error = client.get_tags_by_categories().unwrap_err();
+/// assert!(error.is_retryable());
+/// ```
+#[derive(Clone)]
+pub struct DflowPredictionApiClient {
+    inner: AsyncClient,
+    runtime: Arc<Runtime>,
+}
+
+impl DflowPredictionApiClient {
+    /// Create a new blocking client, see [`AsyncClient::new`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the API key isn't a valid header value or the runtime
+    /// can't be created.
+    pub fn new(base_url: String, api_key: String) -> Self {
+        Self::from_async(AsyncClient::new(base_url, api_key))
+    }
+
+    /// Create a new blocking client, see [`AsyncClient::try_new`].
+    pub fn try_new(base_url: String, api_key: String) -> Result<Self> {
+        Ok(Self::from_async(AsyncClient::try_new(base_url, api_key)?))
+    }
+
+    /// Create a new blocking client with custom HTTP configuration, see
+    /// [`AsyncClient::with_config`].
+    pub fn with_config(
+        base_url: String,
+        api_key: String,
+        config: ClientConfig,
+    ) -> Self {
+        Self::from_async(AsyncClient::with_config(base_url, api_key, config))
+    }
+
+    /// Create a new blocking client with custom HTTP configuration, see
+    /// [`AsyncClient::try_with_config`].
+    pub fn try_with_config(
+        base_url: String,
+        api_key: String,
+        config: ClientConfig,
+    ) -> Result<Self> {
+        Ok(Self::from_async(AsyncClient::try_with_config(
+            base_url, api_key, config,
+        )?))
+    }
+
+    /// Create a new blocking client from shared configuration, see
+    /// [`AsyncClient::with_shared_config`].
+    pub fn with_shared_config(
+        api_key: String,
+        config: &DflowConfig,
+    ) -> Result<Self> {
+        Ok(Self::from_async(AsyncClient::with_shared_config(
+            api_key, config,
+        )?))
+    }
+
+    /// Create a new blocking client with the default base URL.
+    pub fn with_default_url(api_key: String) -> Self {
+        Self::from_async(AsyncClient::with_default_url(api_key))
+    }
+
+    /// Wrap an async client.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the runtime can't be created.
+    pub fn from_async(inner: AsyncClient) -> Self {
+        Self {
+            inner,
+            runtime: Arc::new(blocking_runtime()),
+        }
+    }
+
+    /// See [`AsyncClient::with_debug_requests`].
+    pub fn with_debug_requests(mut self, enabled: bool) -> Self {
+        self.inner = self.inner.with_debug_requests(enabled);
+        self
+    }
+
+    /// The wrapped async client.
+    pub fn as_async(&self) -> &AsyncClient {
+        &self.inner
+    }
+
+    // =========================================================================
+    // API Endpoints
+    // =========================================================================
+
+    /// Blocking version of [`AsyncClient::get_event`].
+    pub fn get_event(
+        &self,
+        event_id: &str,
+        with_nested_markets: Option<bool>,
+    ) -> Result<Event> {
+        self.runtime
+            .block_on(self.inner.get_event(event_id, with_nested_markets))
+    }
+
+    /// Blocking version of [`AsyncClient::get_event_markets`].
+    pub fn get_event_markets(&self, event_id: &str) -> Result<Vec<Market>> {
+        self.runtime
+            .block_on(self.inner.get_event_markets(event_id))
+    }
+
+    /// Blocking version of [`AsyncClient::get_events`].
+    pub fn get_events(
+        &self,
+        params: Option<GetEventsParams>,
+    ) -> Result<EventsResponse> {
+        self.runtime.block_on(self.inner.get_events(params))
+    }
+
+    /// Blocking version of [`AsyncClient::get_events_by_series`].
+    pub fn get_events_by_series(
+        &self,
+        series_tickers: &[String],
+        params: Option<GetEventsParams>,
+    ) -> Result<EventsResponse> {
+        self.runtime
+            .block_on(self.inner.get_events_by_series(series_tickers, params))
+    }
+
+    /// Blocking version of [`AsyncClient::get_event_forecast_percentile_history`].
+    pub fn get_event_forecast_percentile_history(
+        &self,
+        series_ticker: &str,
+        event_id: &str,
+        params: Option<GetForecastPercentileHistoryParams>,
+    ) -> Result<ForecastPercentileHistoryResponse> {
+        self.runtime
+            .block_on(self.inner.get_event_forecast_percentile_history(
+                series_ticker,
+                event_id,
+                params,
+            ))
+    }
+
+    /// Blocking version of [`AsyncClient::get_event_forecast_percentile_history_by_mint`].
+    pub fn get_event_forecast_percentile_history_by_mint(
+        &self,
+        mint: &str,
+        params: Option<GetForecastPercentileHistoryParams>,
+    ) -> Result<ForecastPercentileHistoryResponse> {
+        self.runtime.block_on(
+            self.inner
+                .get_event_forecast_percentile_history_by_mint(mint, params),
+        )
+    }
+
+    /// Blocking version of [`AsyncClient::get_event_candlesticks`].
+    pub fn get_event_candlesticks(
+        &self,
+        ticker: &str,
+        params: Option<GetCandlesticksParams>,
+    ) -> Result<CandlesticksResponse> {
+        self.runtime
+            .block_on(self.inner.get_event_candlesticks(ticker, params))
+    }
+
+    /// Blocking version of [`AsyncClient::get_event_candlesticks_many`].
+    pub fn get_event_candlesticks_many(
+        &self,
+        tickers: &[String],
+        params: Option<GetCandlesticksParams>,
+        include_empty: bool,
+    ) -> Result<HashMap<String, CandlesticksResponse>> {
+        self.runtime
+            .block_on(self.inner.get_event_candlesticks_many(
+                tickers,
+                params,
+                include_empty,
+            ))
+    }
+
+    /// Blocking version of [`AsyncClient::get_market`].
+    pub fn get_market(&self, market_id: &str) -> Result<Market> {
+        self.runtime.block_on(self.inner.get_market(market_id))
+    }
+
+    /// Blocking version of [`AsyncClient::get_market_by_mint`].
+    pub fn get_market_by_mint(&self, mint: &str) -> Result<Market> {
+        self.runtime.block_on(self.inner.get_market_by_mint(mint))
+    }
+
+    /// Blocking version of [`AsyncClient::get_markets_by_mints`].
+    pub fn get_markets_by_mints(
+        &self,
+        mints: &[String],
+    ) -> Vec<Result<Market>> {
+        self.runtime
+            .block_on(self.inner.get_markets_by_mints(mints))
+    }
+
+    /// Blocking version of [`AsyncClient::get_markets_by_mints_with_concurrency`].
+    pub fn get_markets_by_mints_with_concurrency(
+        &self,
+        mints: &[String],
+        concurrency: usize,
+    ) -> Vec<Result<Market>> {
+        self.runtime.block_on(
+            self.inner
+                .get_markets_by_mints_with_concurrency(mints, concurrency),
+        )
+    }
+
+    /// Blocking version of [`AsyncClient::value_holdings`].
+    pub fn value_holdings(
+        &self,
+        holdings: &HashMap<String, i64>,
+    ) -> Result<PortfolioValue> {
+        self.runtime.block_on(self.inner.value_holdings(holdings))
+    }
+
+    /// Blocking version of [`AsyncClient::get_markets`].
+    pub fn get_markets(
+        &self,
+        params: Option<GetMarketsParams>,
+    ) -> Result<MarketsResponse> {
+        self.runtime.block_on(self.inner.get_markets(params))
+    }
+
+    /// Blocking version of [`AsyncClient::get_all_markets`].
+    pub fn get_all_markets(
+        &self,
+        params: Option<GetMarketsParams>,
+        max_pages: Option<usize>,
+    ) -> Result<Vec<Market>> {
+        self.runtime
+            .block_on(self.inner.get_all_markets(params, max_pages))
+    }
+
+    /// Blocking version of [`AsyncClient::get_markets_batch`].
+    pub fn get_markets_batch(
+        &self,
+        tickers: &[String],
+    ) -> Result<MarketsResponse> {
+        self.runtime.block_on(self.inner.get_markets_batch(tickers))
+    }
+
+    /// Blocking version of [`AsyncClient::get_outcome_mints`].
+    pub fn get_outcome_mints(
+        &self,
+        params: Option<GetOutcomeMintsParams>,
+    ) -> Result<OutcomeMintsResponse> {
+        self.runtime.block_on(self.inner.get_outcome_mints(params))
+    }
+
+    /// Blocking version of [`AsyncClient::filter_outcome_mints`].
+    pub fn filter_outcome_mints(
+        &self,
+        addresses: &[String],
+    ) -> Result<FilterOutcomeMintsResponse> {
+        self.runtime
+            .block_on(self.inner.filter_outcome_mints(addresses))
+    }
+
+    /// Blocking version of [`AsyncClient::get_market_candlesticks`].
+    pub fn get_market_candlesticks(
+        &self,
+        ticker: &str,
+        params: Option<GetCandlesticksParams>,
+    ) -> Result<CandlesticksResponse> {
+        self.runtime
+            .block_on(self.inner.get_market_candlesticks(ticker, params))
+    }
+
+    /// Blocking version of [`AsyncClient::get_market_candlesticks_by_mint`].
+    pub fn get_market_candlesticks_by_mint(
+        &self,
+        mint: &str,
+        params: Option<GetCandlesticksParams>,
+    ) -> Result<CandlesticksResponse> {
+        self.runtime
+            .block_on(self.inner.get_market_candlesticks_by_mint(mint, params))
+    }
+
+    /// Blocking version of [`AsyncClient::get_orderbook`].
+    pub fn get_orderbook(&self, market_ticker: &str) -> Result<Orderbook> {
+        self.runtime
+            .block_on(self.inner.get_orderbook(market_ticker))
+    }
+
+    /// Blocking version of [`AsyncClient::get_orderbook_by_mint`].
+    pub fn get_orderbook_by_mint(&self, mint: &str) -> Result<Orderbook> {
+        self.runtime
+            .block_on(self.inner.get_orderbook_by_mint(mint))
+    }
+
+    /// Blocking version of [`AsyncClient::get_event_depth`].
+    pub fn get_event_depth(
+        &self,
+        event_ticker: &str,
+    ) -> Result<HashMap<String, Orderbook>> {
+        self.runtime
+            .block_on(self.inner.get_event_depth(event_ticker))
+    }
+
+    /// Blocking version of [`AsyncClient::get_trades`].
+    pub fn get_trades(
+        &self,
+        params: Option<GetTradesParams>,
+    ) -> Result<TradesResponse> {
+        self.runtime.block_on(self.inner.get_trades(params))
+    }
+
+    /// Blocking version of [`AsyncClient::trades_since`].
+    pub fn trades_since(
+        &self,
+        ticker: &str,
+        min_ts: i64,
+    ) -> Result<Vec<Trade>> {
+        self.runtime
+            .block_on(self.inner.trades_since(ticker, min_ts))
+    }
+
+    /// Blocking version of [`AsyncClient::trades_in_range`].
+    pub fn trades_in_range(
+        &self,
+        ticker: &str,
+        start_ts: i64,
+        end_ts: i64,
+    ) -> Result<Vec<Trade>> {
+        self.runtime
+            .block_on(self.inner.trades_in_range(ticker, start_ts, end_ts))
+    }
+
+    /// Blocking version of [`AsyncClient::get_trades_by_mint`].
+    pub fn get_trades_by_mint(
+        &self,
+        mint: &str,
+        params: Option<GetTradesParams>,
+    ) -> Result<TradesResponse> {
+        self.runtime
+            .block_on(self.inner.get_trades_by_mint(mint, params))
+    }
+
+    /// Blocking version of [`AsyncClient::get_live_data`].
+    pub fn get_live_data(
+        &self,
+        milestone_ids: &[String],
+    ) -> Result<LiveDataResponse> {
+        self.runtime
+            .block_on(self.inner.get_live_data(milestone_ids))
+    }
+
+    /// Blocking version of [`AsyncClient::get_live_data_by_event`].
+    pub fn get_live_data_by_event(
+        &self,
+        event_ticker: &str,
+    ) -> Result<LiveDataResponse> {
+        self.runtime
+            .block_on(self.inner.get_live_data_by_event(event_ticker))
+    }
+
+    /// Blocking version of [`AsyncClient::get_live_data_by_mint`].
+    pub fn get_live_data_by_mint(
+        &self,
+        mint: &str,
+    ) -> Result<LiveDataResponse> {
+        self.runtime
+            .block_on(self.inner.get_live_data_by_mint(mint))
+    }
+
+    /// Blocking version of [`AsyncClient::get_live_data_for_mints`].
+    pub fn get_live_data_for_mints(
+        &self,
+        mints: &[String],
+    ) -> Result<HashMap<String, LiveDataResponse>> {
+        self.runtime
+            .block_on(self.inner.get_live_data_for_mints(mints))
+    }
+
+    /// Blocking version of [`AsyncClient::get_series`].
+    pub fn get_series(
+        &self,
+        params: Option<GetSeriesParams>,
+    ) -> Result<SeriesResponse> {
+        self.runtime.block_on(self.inner.get_series(params))
+    }
+
+    /// Blocking version of [`AsyncClient::get_series_grouped`].
+    pub fn get_series_grouped(
+        &self,
+        params: Option<GetSeriesParams>,
+    ) -> Result<HashMap<String, Vec<Series>>> {
+        self.runtime.block_on(self.inner.get_series_grouped(params))
+    }
+
+    /// Blocking version of [`AsyncClient::get_series_by_ticker`].
+    pub fn get_series_by_ticker(&self, series_ticker: &str) -> Result<Series> {
+        self.runtime
+            .block_on(self.inner.get_series_by_ticker(series_ticker))
+    }
+
+    /// Blocking version of [`AsyncClient::get_tags_by_categories`].
+    pub fn get_tags_by_categories(&self) -> Result<TagsByCategoriesResponse> {
+        self.runtime.block_on(self.inner.get_tags_by_categories())
+    }
+
+    /// Blocking version of [`AsyncClient::get_tags_for_category`].
+    pub fn get_tags_for_category(
+        &self,
+        category: impl AsRef<str>,
+    ) -> Result<Vec<String>> {
+        self.runtime
+            .block_on(self.inner.get_tags_for_category(category))
+    }
+
+    /// Blocking version of [`AsyncClient::get_filters_by_sports`].
+    pub fn get_filters_by_sports(&self) -> Result<FiltersBySportsResponse> {
+        self.runtime.block_on(self.inner.get_filters_by_sports())
+    }
+
+    /// Blocking version of [`AsyncClient::search_events`].
+    pub fn search_events(
+        &self,
+        params: SearchParams,
+    ) -> Result<SearchResponse> {
+        self.runtime.block_on(self.inner.search_events(params))
+    }
+}
@@ -0,0 +1,180 @@
+//! Caching wrapper for rarely changing Prediction API metadata.
+//!
+//! Series and the tag taxonomy change far less often than markets, yet are
+//! looked up for every market a UI renders. [`CachedPredictionClient`]
+//! memoizes those lookups in memory for a configurable time to live.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+use super::{DflowPredictionApiClient, Result, types::*};
+
+/// Default time to live of cached entries (5 minutes)
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(300);
+
+/// Key of a cached entry, for [`CachedPredictionClient::invalidate`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CacheKey {
+    /// A series, by ticker
+    Series(String),
+    /// The tags by categories map
+    TagsByCategories,
+}
+
+/// A cached value and when it was fetched.
+struct Entry<T> {
+    value: T,
+    fetched_at: Instant,
+}
+
+#[derive(Default)]
+struct Cache {
+    series: HashMap<String, Entry<Series>>,
+    tags_by_categories: Option<Entry<TagsByCategoriesResponse>>,
+}
+
+/// Prediction API client that caches series and tag lookups.
+///
+/// Only [`get_series_by_ticker`](Self::get_series_by_ticker) and
+/// [`get_tags_by_categories`](Self::get_tags_by_categories) are cached;
+/// every other endpoint is reached through [`inner`](Self::inner). Errors
+/// are never cached. Concurrent misses for the same key may each fetch,
+/// the last response wins. Clones share the cache.
+///
+/// Expired series are evicted whenever a series is fetched, so the cache
+/// only holds the series fetched within the last TTL.
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use dflow_api_client::prediction::{
+///     DflowPredictionApiClient,
+///     cached::{CacheKey, CachedPredictionClient},
+/// };
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = CachedPredictionClient::new(
+///     DflowPredictionApiClient::with_default_url("api-key".to_string()),
+///     Duration::from_secs(600),
+/// );
+///
+/// // Fetched once, then served from the cache for ten minutes
+/// let series = client.get_series_by_ticker("KXBTC").await?;
+///
+/// // Force a refetch
+/// client.invalidate(&CacheKey::Series("KXBTC".to_string()));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct CachedPredictionClient {
+    inner: DflowPredictionApiClient,
+    ttl: Duration,
+    cache: Arc<Mutex<Cache>>,
+}
+
+impl CachedPredictionClient {
+    /// Wrap a client, caching entries for `ttl`.
+    pub fn new(inner: DflowPredictionApiClient, ttl: Duration) -> Self {
+        Self {
+            inner,
+            ttl,
+            cache: Arc::default(),
+        }
+    }
+
+    /// Wrap a client, caching entries for [`DEFAULT_CACHE_TTL`].
+    pub fn with_default_ttl(inner: DflowPredictionApiClient) -> Self {
+        Self::new(inner, DEFAULT_CACHE_TTL)
+    }
+
+    /// The wrapped client, for uncached endpoints.
+    pub fn inner(&self) -> &DflowPredictionApiClient {
+        &self.inner
+    }
+
+    /// Time to live of cached entries.
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Get a series by ticker, from the cache if fresh.
+    ///
+    /// See [`DflowPredictionApiClient::get_series_by_ticker`].
+    pub async fn get_series_by_ticker(
+        &self,
+        series_ticker: &str,
+    ) -> Result<Series> {
+        let cached = self.fresh(|cache| cache.series.get(series_ticker));
+        if let Some(series) = cached {
+            return Ok(series);
+        }
+
+        let series = self.inner.get_series_by_ticker(series_ticker).await?;
+        let mut cache = self.lock();
+        cache
+            .series
+            .retain(|_, entry| entry.fetched_at.elapsed() < self.ttl);
+        cache.series.insert(
+            series_ticker.to_string(),
+            Entry {
+                value: series.clone(),
+                fetched_at: Instant::now(),
+            },
+        );
+        Ok(series)
+    }
+
+    /// Get the tags by categories map, from the cache if fresh.
+    ///
+    /// See [`DflowPredictionApiClient::get_tags_by_categories`].
+    pub async fn get_tags_by_categories(
+        &self,
+    ) -> Result<TagsByCategoriesResponse> {
+        let cached = self.fresh(|cache| cache.tags_by_categories.as_ref());
+        if let Some(tags) = cached {
+            return Ok(tags);
+        }
+
+        let tags = self.inner.get_tags_by_categories().await?;
+        self.lock().tags_by_categories = Some(Entry {
+            value: tags.clone(),
+            fetched_at: Instant::now(),
+        });
+        Ok(tags)
+    }
+
+    /// Drop a cached entry, so the next lookup fetches it again.
+    pub fn invalidate(&self, key: &CacheKey) {
+        let mut cache = self.lock();
+        match key {
+            CacheKey::Series(ticker) => {
+                cache.series.remove(ticker);
+            }
+            CacheKey::TagsByCategories => cache.tags_by_categories = None,
+        }
+    }
+
+    /// Drop all cached entries.
+    pub fn clear(&self) {
+        *self.lock() = Cache::default();
+    }
+
+    /// Clone of a cached value, if present and within the TTL.
+    fn fresh<T: Clone>(
+        &self,
+        entry: impl FnOnce(&Cache) -> Option<&Entry<T>>,
+    ) -> Option<T> {
+        entry(&self.lock())
+            .filter(|entry| entry.fetched_at.elapsed() < self.ttl)
+            .map(|entry| entry.value.clone())
+    }
+
+    /// The lock is never held across an await.
+    fn lock(&self) -> MutexGuard<'_, Cache> {
+        self.cache.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
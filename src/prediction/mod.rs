@@ -1,20 +1,49 @@
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod cached;
 pub mod types;
 
+#[cfg(feature = "chrono")]
+mod time;
 #[cfg(feature = "websocket")]
 pub mod websocket;
 
-use crate::common::{DflowHttpClient, build_query_string, create_http_client};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    time::Duration,
+};
+
+use futures_util::stream::{self, BoxStream, StreamExt, TryStreamExt};
+
+use crate::common::{
+    ClientConfig, DEFAULT_CONCURRENCY, DflowConfig, DflowHttpClient,
+    RateLimiter, RetryPolicy, build_query_string, fetch_concurrent,
+    follow_pages, try_create_http_client,
+};
 
 /// Error type for the DFlow Prediction Market API.
 pub type DflowPredictionApiError = crate::common::DflowApiError;
 /// Result type for the DFlow Prediction Market API.
 pub type Result<T> = crate::common::Result<T>;
 use reqwest::Client;
+use tokio::time::{Interval, MissedTickBehavior};
 pub use types::*;
 
 /// Default base URL for the DFlow Prediction Market API
 pub const DEFAULT_BASE_URL: &str = "https://prediction-markets-api.dflow.net";
 
+/// Maximum number of series tickers accepted by the events filter
+pub const MAX_SERIES_TICKERS: usize = 25;
+
+/// Maximum number of addresses per `filter_outcome_mints` request
+pub const MAX_FILTER_ADDRESSES: usize = 200;
+
+/// Maximum number of milestone IDs per `get_live_data` request
+pub const MAX_LIVE_DATA_MILESTONES: usize = 100;
+
+/// Maximum `limit` of a `get_trades` request
+pub const MAX_TRADES_LIMIT: i32 = 1000;
+
 /// Client for interacting with the DFlow Prediction Market Metadata API.
 ///
 /// # Example
@@ -37,6 +66,8 @@ pub const DEFAULT_BASE_URL: &str = "https://prediction-markets-api.dflow.net";
 pub struct DflowPredictionApiClient {
     http_client: Client,
     base_url: String,
+    debug_requests: bool,
+    config: ClientConfig,
 }
 
 impl DflowHttpClient for DflowPredictionApiClient {
@@ -47,6 +78,22 @@ impl DflowHttpClient for DflowPredictionApiClient {
     fn base_url(&self) -> &str {
         &self.base_url
     }
+
+    fn debug_requests(&self) -> bool {
+        self.debug_requests
+    }
+
+    fn operation_timeout(&self, operation: &str) -> Option<Duration> {
+        self.config.timeout_for(operation)
+    }
+
+    fn retry_policy(&self) -> Option<&RetryPolicy> {
+        self.config.retry_policy.as_ref()
+    }
+
+    fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.config.rate_limiter.as_ref()
+    }
 }
 
 impl DflowPredictionApiClient {
@@ -56,10 +103,147 @@ impl DflowPredictionApiClient {
     ///
     /// * `base_url` - Base URL for the API (e.g., "https://prediction-markets-api.dflow.net")
     /// * `api_key` - API key for authentication
+    ///
+    /// # Panics
+    ///
+    /// Panics if the API key isn't a valid header value. Use `try_new` to
+    /// handle this as an error.
     pub fn new(base_url: String, api_key: String) -> Self {
+        Self::with_config(base_url, api_key, ClientConfig::default())
+    }
+
+    /// Create a new DFlow Prediction API client, returning an error instead of panicking.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - Base URL for the API (e.g., "https://prediction-markets-api.dflow.net")
+    /// * `api_key` - API key for authentication
+    ///
+    /// # Returns
+    ///
+    /// The client, or `InvalidParameter` if the API key contains bytes that
+    /// aren't allowed in a header (e.g. a trailing newline).
+    pub fn try_new(base_url: String, api_key: String) -> Result<Self> {
+        Self::try_with_config(base_url, api_key, ClientConfig::default())
+    }
+
+    /// Create a new client with custom HTTP configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - Base URL for the API
+    /// * `api_key` - API key for authentication
+    /// * `config` - HTTP configuration, including per-operation timeouts
+    ///
+    /// # Panics
+    ///
+    /// Panics if the API key isn't a valid header value. Use
+    /// `try_with_config` to handle this as an error.
+    pub fn with_config(
+        base_url: String,
+        api_key: String,
+        config: ClientConfig,
+    ) -> Self {
+        Self::try_with_config(base_url, api_key, config)
+            .expect("Failed to build HTTP client")
+    }
+
+    /// Create a new client with custom HTTP configuration, returning an
+    /// error instead of panicking.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - Base URL for the API
+    /// * `api_key` - API key for authentication
+    /// * `config` - HTTP configuration, including per-operation timeouts
+    pub fn try_with_config(
+        base_url: String,
+        api_key: String,
+        config: ClientConfig,
+    ) -> Result<Self> {
+        Ok(Self {
+            http_client: try_create_http_client(&api_key, &config)?,
+            base_url,
+            debug_requests: false,
+            config,
+        })
+    }
+
+    /// Create a new client from configuration shared with the other
+    /// DFlow clients.
+    ///
+    /// Uses `config.prediction_base_url`, or `DEFAULT_BASE_URL` if unset.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - API key for authentication
+    /// * `config` - Shared configuration
+    ///
+    /// # Returns
+    ///
+    /// The client, or `InvalidParameter` if the API key, a header or the
+    /// proxy URL is invalid.
+    pub fn with_shared_config(
+        api_key: String,
+        config: &DflowConfig,
+    ) -> Result<Self> {
+        let base_url = config
+            .prediction_base_url
+            .clone()
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+        Ok(
+            Self::try_with_config(base_url, api_key, config.http.clone())?
+                .with_debug_requests(config.debug_requests),
+        )
+    }
+
+    /// Create a new client from a pre-built `reqwest::Client`.
+    ///
+    /// Lets several DFlow clients share one connection pool, proxy and TLS
+    /// configuration. The API key isn't added to requests, so the supplied
+    /// client must already send it in an `x-api-key` default header.
+    /// Settings of [`ClientConfig`] don't apply: timeouts, headers and
+    /// proxies are whatever the supplied client was built with, and failed
+    /// requests aren't retried.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - HTTP client sending the `x-api-key` header
+    /// * `base_url` - Base URL for the API
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dflow_api_client::{swap::DflowSwapApiClient, prediction::DflowPredictionApiClient};
+    /// use reqwest::header::{HeaderMap, HeaderValue};
+    ///
+    /// let mut headers = HeaderMap::new();
+    /// headers.insert("x-api-key", HeaderValue::from_static("api-key"));
+    /// let http = reqwest::Client::builder()
+    ///     .default_headers(headers)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // Both clients share the same connection pool
+    /// let client = DflowPredictionApiClient::from_client(
+    ///     http.clone(),
+    ///     "https://example.com".to_string(),
+    /// );
+    /// let other = DflowSwapApiClient::from_client(
+    ///     http,
+    ///     "https://example.com".to_string(),
+    /// );
+    /// ```
+    pub fn from_client(client: Client, base_url: String) -> Self {
         Self {
-            http_client: create_http_client(&api_key),
+            http_client: client,
             base_url,
+            debug_requests: false,
+            // The supplied client's own timeout applies
+            config: ClientConfig {
+                timeout: None,
+                ..Default::default()
+            },
         }
     }
 
@@ -72,6 +256,21 @@ impl DflowPredictionApiClient {
         Self::new(DEFAULT_BASE_URL.to_string(), api_key)
     }
 
+    /// Include a redacted summary of the request body in POST errors.
+    ///
+    /// Useful for diagnosing rejected requests. Only mints, amounts,
+    /// slippage and tickers are included; signatures, public keys,
+    /// transactions and addresses are never part of the summary. Disabled
+    /// by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to include the request summary
+    pub fn with_debug_requests(mut self, enabled: bool) -> Self {
+        self.debug_requests = enabled;
+        self
+    }
+
     // =========================================================================
     // Events API Endpoints
     // =========================================================================
@@ -96,8 +295,38 @@ impl DflowPredictionApiClient {
             with_nested_markets.map(|v| v.to_string()),
         )]);
 
-        self.get(&format!("/api/v1/event/{}{}", event_id, query))
-            .await
+        self.get_op(
+            "get_event",
+            &format!("/api/v1/event/{}{}", event_id, query),
+        )
+        .await
+    }
+
+    /// Get the markets of an event.
+    ///
+    /// Fetches the event with nested markets and returns just the markets.
+    ///
+    /// # Arguments
+    ///
+    /// * `event_id` - Event ticker ID
+    ///
+    /// # Returns
+    ///
+    /// The event's markets, or `InvalidData` if the response carries no
+    /// markets field.
+    pub async fn get_event_markets(
+        &self,
+        event_id: &str,
+    ) -> Result<Vec<Market>> {
+        self.get_event(event_id, Some(true))
+            .await?
+            .markets
+            .ok_or_else(|| {
+                DflowPredictionApiError::InvalidData(format!(
+                    "event {} returned without nested markets",
+                    event_id
+                ))
+            })
     }
 
     /// Get a paginated list of events.
@@ -108,12 +337,14 @@ impl DflowPredictionApiClient {
     ///
     /// # Returns
     ///
-    /// A paginated list of events.
+    /// A paginated list of events, or `InvalidParameter` if the params fail
+    /// [`GetEventsParams::validate`].
     pub async fn get_events(
         &self,
         params: Option<GetEventsParams>,
     ) -> Result<EventsResponse> {
         let params = params.unwrap_or_default();
+        params.validate()?;
 
         let query = build_query_string(&[
             ("limit", params.limit.map(|v| v.to_string())),
@@ -131,7 +362,114 @@ impl DflowPredictionApiClient {
             ("sort", params.sort.map(|v| v.as_str().to_string())),
         ]);
 
-        self.get(&format!("/api/v1/events{}", query)).await
+        self.get_op("get_events", &format!("/api/v1/events{}", query))
+            .await
+    }
+
+    /// Get events of the given series.
+    ///
+    /// Same as [`get_events`](Self::get_events) with `series_tickers` set
+    /// from the slice, replacing any value in `params`.
+    ///
+    /// # Arguments
+    ///
+    /// * `series_tickers` - Series tickers (1 to `MAX_SERIES_TICKERS`)
+    /// * `params` - Additional query parameters
+    ///
+    /// # Returns
+    ///
+    /// A paginated list of events, or `InvalidParameter` if no tickers or
+    /// more than `MAX_SERIES_TICKERS` are given.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dflow_api_client::prediction::{
+    ///     DflowPredictionApiClient, DflowPredictionApiError, MAX_SERIES_TICKERS,
+    /// };
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let client =
+    ///     DflowPredictionApiClient::with_default_url("api-key".to_string());
+    ///
+    /// // Rejected before any request is made
+    /// let tickers: Vec<String> = (0..=MAX_SERIES_TICKERS)
+    ///     .map(|i| format!("SERIES-{}", i))
+    ///     .collect();
+    /// let result = client.get_events_by_series(&tickers, None).await;
+    /// assert!(matches!(
+    ///     result,
+    ///     Err(DflowPredictionApiError::InvalidParameter(_))
+    /// ));
+    /// # }
+    /// ```
+    pub async fn get_events_by_series(
+        &self,
+        series_tickers: &[String],
+        params: Option<GetEventsParams>,
+    ) -> Result<EventsResponse> {
+        if series_tickers.is_empty()
+            || series_tickers.len() > MAX_SERIES_TICKERS
+        {
+            return Err(DflowPredictionApiError::InvalidParameter(format!(
+                "expected 1 to {} series tickers, got {}",
+                MAX_SERIES_TICKERS,
+                series_tickers.len()
+            )));
+        }
+
+        let params = GetEventsParams {
+            series_tickers: Some(series_tickers.join(",")),
+            ..params.unwrap_or_default()
+        };
+        self.get_events(Some(params)).await
+    }
+
+    /// Stream all events matching the filters, following pagination cursors.
+    ///
+    /// Fetches pages with `get_events`, passing each returned cursor back
+    /// with the original filters, and yields the events one by one. The
+    /// stream ends when a page has no cursor or no events. If a page fails,
+    /// its error is yielded as the last item.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Filters and page size applied to every page
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use dflow_api_client::prediction::{
+    ///     DflowPredictionApiClient, GetEventsParams, MarketStatus,
+    /// };
+    /// use futures_util::StreamExt;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client =
+    ///     DflowPredictionApiClient::with_default_url("api-key".to_string());
+    ///
+    /// let mut events = client.events_stream(GetEventsParams {
+    ///     status: Some(MarketStatus::Active),
+    ///     ..Default::default()
+    /// });
+    /// while let Some(event) = events.next().await {
+    ///     println!("{}", event?.ticker);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn events_stream(
+        &self,
+        params: GetEventsParams,
+    ) -> BoxStream<'_, Result<Event>> {
+        follow_pages(move |cursor| {
+            let mut params = params.clone();
+            if let Some(cursor) = cursor {
+                params.cursor = cursor.as_offset();
+            }
+            self.get_events(Some(params))
+        })
     }
 
     /// Get forecast percentile history for an event.
@@ -159,15 +497,16 @@ impl DflowPredictionApiClient {
             ("endTs", params.end_ts.map(|v| v.to_string())),
             (
                 "periodInterval",
-                params.period_interval.map(|v| v.to_string()),
+                params.period_interval.map(|v| v.as_i32().to_string()),
             ),
         ]);
 
-        self.get(&format!(
+        let endpoint = format!(
             "/api/v1/event/{series_ticker}/{event_id}/forecast_percentile_history{}",
             query
-        ))
-        .await
+        );
+        self.get_op("get_event_forecast_percentile_history", &endpoint)
+            .await
     }
 
     /// Get forecast percentile history by mint address.
@@ -193,14 +532,17 @@ impl DflowPredictionApiClient {
             ("endTs", params.end_ts.map(|v| v.to_string())),
             (
                 "periodInterval",
-                params.period_interval.map(|v| v.to_string()),
+                params.period_interval.map(|v| v.as_i32().to_string()),
             ),
         ]);
 
-        self.get(&format!(
-            "/api/v1/event/by-mint/{mint}/forecast_percentile_history{}",
-            query
-        ))
+        self.get_op(
+            "get_event_forecast_percentile_history_by_mint",
+            &format!(
+                "/api/v1/event/by-mint/{mint}/forecast_percentile_history{}",
+                query
+            ),
+        )
         .await
     }
 
@@ -226,12 +568,69 @@ impl DflowPredictionApiClient {
             ("endTs", params.end_ts.map(|v| v.to_string())),
             (
                 "periodInterval",
-                params.period_interval.map(|v| v.to_string()),
+                params.period_interval.map(|v| v.as_i32().to_string()),
             ),
         ]);
 
-        self.get(&format!("/api/v1/event/{ticker}/candlesticks{}", query))
-            .await
+        self.get_op(
+            "get_event_candlesticks",
+            &format!("/api/v1/event/{ticker}/candlesticks{}", query),
+        )
+        .await
+    }
+
+    /// Get candlesticks for several events concurrently.
+    ///
+    /// Issues `get_event_candlesticks` for every ticker with the same
+    /// parameters, keeping at most `DEFAULT_CONCURRENCY` requests in flight.
+    /// Events without candle data (an empty response or `NotFound`) are
+    /// included with an empty candlestick list if `include_empty` is set and
+    /// left out otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `tickers` - Event ticker IDs
+    /// * `params` - Query parameters applied to every event
+    /// * `include_empty` - Whether to include events without candle data
+    ///
+    /// # Returns
+    ///
+    /// Candlesticks keyed by event ticker, or the first error other than
+    /// `NotFound`.
+    pub async fn get_event_candlesticks_many(
+        &self,
+        tickers: &[String],
+        params: Option<GetCandlesticksParams>,
+        include_empty: bool,
+    ) -> Result<HashMap<String, CandlesticksResponse>> {
+        let results = fetch_concurrent(
+            tickers.iter().cloned(),
+            DEFAULT_CONCURRENCY,
+            |ticker| {
+                let params = params.clone();
+                async move { self.get_event_candlesticks(&ticker, params).await }
+            },
+        )
+        .await;
+
+        let mut candlesticks = HashMap::new();
+        for (ticker, result) in results {
+            let response = match result {
+                Ok(response) => response,
+                Err(DflowPredictionApiError::NotFound(_)) => {
+                    CandlesticksResponse {
+                        candlesticks: Vec::new(),
+                    }
+                }
+                Err(e) => return Err(e),
+            };
+
+            if include_empty || !response.candlesticks.is_empty() {
+                candlesticks.insert(ticker, response);
+            }
+        }
+
+        Ok(candlesticks)
     }
 
     // =========================================================================
@@ -248,7 +647,8 @@ impl DflowPredictionApiClient {
     ///
     /// The market with the given ticker ID.
     pub async fn get_market(&self, market_id: &str) -> Result<Market> {
-        self.get(&format!("/api/v1/market/{}", market_id)).await
+        self.get_op("get_market", &format!("/api/v1/market/{}", market_id))
+            .await
     }
 
     /// Get a market by its mint address.
@@ -261,7 +661,143 @@ impl DflowPredictionApiClient {
     ///
     /// The market associated with the mint.
     pub async fn get_market_by_mint(&self, mint: &str) -> Result<Market> {
-        self.get(&format!("/api/v1/market/by-mint/{}", mint)).await
+        self.get_op(
+            "get_market_by_mint",
+            &format!("/api/v1/market/by-mint/{}", mint),
+        )
+        .await
+    }
+
+    /// Get the markets of several mints concurrently.
+    ///
+    /// The API has no batch lookup by mint, so this calls
+    /// `get_market_by_mint` for every mint, keeping at most
+    /// `DEFAULT_CONCURRENCY` requests in flight. A failed lookup doesn't
+    /// affect the others.
+    ///
+    /// # Arguments
+    ///
+    /// * `mints` - Mint addresses (yes or no outcome mints)
+    ///
+    /// # Returns
+    ///
+    /// The result of every lookup, in the order of `mints`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use dflow_api_client::prediction::DflowPredictionApiClient;
+    ///
+    /// # async fn example() {
+    /// let client =
+    ///     DflowPredictionApiClient::with_default_url("api-key".to_string());
+    ///
+    /// let mints = vec!["MINT_1".to_string(), "MINT_2".to_string()];
+    /// let markets = client.get_markets_by_mints(&mints).await;
+    /// for (mint, market) in mints.iter().zip(markets) {
+    ///     match market {
+    ///         Ok(market) => println!("{}: {}", mint, market.ticker),
+    ///         Err(e) => println!("{}: {}", mint, e),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub async fn get_markets_by_mints(
+        &self,
+        mints: &[String],
+    ) -> Vec<Result<Market>> {
+        self.get_markets_by_mints_with_concurrency(mints, DEFAULT_CONCURRENCY)
+            .await
+    }
+
+    /// Get the markets of several mints with at most `concurrency` requests
+    /// in flight.
+    ///
+    /// See [`get_markets_by_mints`](Self::get_markets_by_mints).
+    pub async fn get_markets_by_mints_with_concurrency(
+        &self,
+        mints: &[String],
+        concurrency: usize,
+    ) -> Vec<Result<Market>> {
+        fetch_concurrent(mints, concurrency, |mint| {
+            self.get_market_by_mint(mint)
+        })
+        .await
+        .into_iter()
+        .map(|(_, result)| result)
+        .collect()
+    }
+
+    /// Value outcome token holdings at current market prices.
+    ///
+    /// Looks up the market of every mint with `get_market_by_mint`, keeping
+    /// at most `DEFAULT_CONCURRENCY` requests in flight. Open positions are
+    /// priced at the best bid of their outcome, i.e. what they could be sold
+    /// for; positions in determined markets are worth $1 per contract if
+    /// their outcome won and nothing otherwise. Positions without a price
+    /// are listed with a value of 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `holdings` - Quantity held (in contracts) per outcome mint
+    ///
+    /// # Returns
+    ///
+    /// The total value with a per-position breakdown, the first error from a
+    /// market lookup, or `InvalidData` if a mint is neither outcome mint of
+    /// its market.
+    pub async fn value_holdings(
+        &self,
+        holdings: &HashMap<String, i64>,
+    ) -> Result<PortfolioValue> {
+        let mut mints: Vec<String> = holdings.keys().cloned().collect();
+        mints.sort();
+
+        let results =
+            fetch_concurrent(mints, DEFAULT_CONCURRENCY, |mint| async move {
+                self.get_market_by_mint(&mint).await
+            })
+            .await;
+
+        let mut portfolio = PortfolioValue::default();
+        for (mint, result) in results {
+            let market = result?;
+            let quantity = holdings[&mint];
+
+            let outcome = if market.accounts.yes_mint.as_deref() == Some(&mint)
+            {
+                Side::Yes
+            } else if market.accounts.no_mint.as_deref() == Some(&mint) {
+                Side::No
+            } else {
+                return Err(DflowPredictionApiError::InvalidData(format!(
+                    "mint {} is neither outcome mint of market {}",
+                    mint, market.ticker
+                )));
+            };
+            let price_dollars = match (&market.result, outcome) {
+                (MarketResult::Undetermined, Side::Yes) => market.yes_bid_f64(),
+                (MarketResult::Undetermined, Side::No) => market.no_bid_f64(),
+                (MarketResult::Yes, Side::Yes)
+                | (MarketResult::No, Side::No) => Some(1.0),
+                (MarketResult::Other(_), _) => None,
+                _ => Some(0.0),
+            };
+
+            let value_dollars =
+                price_dollars.map_or(0.0, |price| price * quantity as f64);
+            portfolio.total_dollars += value_dollars;
+            portfolio.positions.push(PositionValue {
+                mint,
+                market_ticker: market.ticker,
+                outcome,
+                quantity,
+                price_dollars,
+                value_dollars,
+            });
+        }
+
+        Ok(portfolio)
     }
 
     /// Get a paginated list of markets.
@@ -272,12 +808,14 @@ impl DflowPredictionApiClient {
     ///
     /// # Returns
     ///
-    /// A paginated list of markets.
+    /// A paginated list of markets, or `InvalidParameter` if the params fail
+    /// [`GetMarketsParams::validate`].
     pub async fn get_markets(
         &self,
         params: Option<GetMarketsParams>,
     ) -> Result<MarketsResponse> {
         let params = params.unwrap_or_default();
+        params.validate()?;
 
         let query = build_query_string(&[
             ("limit", params.limit.map(|v| v.to_string())),
@@ -290,7 +828,167 @@ impl DflowPredictionApiClient {
             ("sort", params.sort.map(|v| v.as_str().to_string())),
         ]);
 
-        self.get(&format!("/api/v1/markets{}", query)).await
+        self.get_op("get_markets", &format!("/api/v1/markets{}", query))
+            .await
+    }
+
+    /// Get all markets matching the filters, following pagination cursors.
+    ///
+    /// Collects [`markets_stream`](Self::markets_stream) until a page has no
+    /// cursor or no markets, or `max_pages` pages were fetched. If any page
+    /// fails, its error is returned rather than the markets of the pages
+    /// before it, so a result is never silently incomplete.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Filters and page size applied to every page
+    /// * `max_pages` - Maximum number of pages to fetch (unbounded if `None`)
+    ///
+    /// # Returns
+    ///
+    /// The markets of all fetched pages, in page order.
+    pub async fn get_all_markets(
+        &self,
+        params: Option<GetMarketsParams>,
+        max_pages: Option<usize>,
+    ) -> Result<Vec<Market>> {
+        if max_pages == Some(0) {
+            return Ok(Vec::new());
+        }
+        let params = params.unwrap_or_default();
+        let mut pages = 0;
+
+        follow_pages(move |cursor| {
+            let mut params = params.clone();
+            if let Some(cursor) = cursor {
+                params.cursor = cursor.as_offset();
+            }
+            pages += 1;
+            let last_page = max_pages.is_some_and(|max| pages >= max);
+
+            async move {
+                let mut response = self.get_markets(Some(params)).await?;
+                if last_page {
+                    response.cursor = None;
+                }
+                Ok(response)
+            }
+        })
+        .try_collect()
+        .await
+    }
+
+    /// Stream all markets matching the filters, following pagination
+    /// cursors.
+    ///
+    /// Fetches pages with `get_markets`, passing each returned cursor back
+    /// with the original filters, and yields the markets one by one. The
+    /// stream ends when a page has no cursor or no markets. If a page fails,
+    /// its error is yielded as the last item. Unlike
+    /// [`get_all_markets`](Self::get_all_markets), markets are available as
+    /// soon as their page arrives.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Filters and page size applied to every page
+    ///
+    /// Consumed like [`events_stream`](Self::events_stream).
+    pub fn markets_stream(
+        &self,
+        params: GetMarketsParams,
+    ) -> BoxStream<'_, Result<Market>> {
+        follow_pages(move |cursor| {
+            let mut params = params.clone();
+            if let Some(cursor) = cursor {
+                params.cursor = cursor.as_offset();
+            }
+            self.get_markets(Some(params))
+        })
+    }
+
+    /// Stream changes to market fields by periodically polling `get_markets`.
+    ///
+    /// Fetches all markets matching `params` every `poll_interval` and
+    /// compares the watched `fields` of each market with the previous poll,
+    /// yielding a `MarketChange` for every field that differs. The first poll
+    /// only records the baseline. Markets that appear for the first time
+    /// produce no changes, and markets that disappear are ignored.
+    ///
+    /// Each poll walks every page with [`markets_stream`](Self::markets_stream).
+    /// If a page fails, the poll yields that error and is not diffed, so
+    /// the baseline stays at the last complete poll; polling resumes on the
+    /// next interval. The stream never ends on its own; drop it to stop
+    /// polling.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Filters applied to every poll
+    /// * `poll_interval` - Time between polls
+    /// * `fields` - Fields to watch (see `MarketField::ALL`)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use dflow_api_client::prediction::{
+    ///     DflowPredictionApiClient, MarketField,
+    /// };
+    /// use futures_util::StreamExt;
+    ///
+    /// # async fn example() {
+    /// let client =
+    ///     DflowPredictionApiClient::with_default_url("api-key".to_string());
+    ///
+    /// let mut changes = client.markets_changes_stream(
+    ///     None,
+    ///     Duration::from_secs(30),
+    ///     &[MarketField::Status, MarketField::YesBid],
+    /// );
+    /// while let Some(change) = changes.next().await {
+    ///     match change {
+    ///         Ok(change) => println!(
+    ///             "{} {:?}: {:?} -> {:?}",
+    ///             change.ticker, change.field, change.old, change.new
+    ///         ),
+    ///         Err(e) => eprintln!("poll failed: {}", e),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn markets_changes_stream(
+        &self,
+        params: Option<GetMarketsParams>,
+        poll_interval: Duration,
+        fields: &[MarketField],
+    ) -> BoxStream<'_, Result<MarketChange>> {
+        let mut timer =
+            tokio::time::interval(poll_interval.max(Duration::from_millis(1)));
+        timer.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        let poller = MarketsPoller {
+            params,
+            fields: fields.to_vec(),
+            timer,
+            snapshot: HashMap::new(),
+            ready: VecDeque::new(),
+        };
+
+        stream::unfold(poller, move |mut poller| async move {
+            loop {
+                if let Some(change) = poller.ready.pop_front() {
+                    return Some((Ok(change), poller));
+                }
+
+                poller.timer.tick().await;
+                let params = poller.params.clone().unwrap_or_default();
+                match self.markets_stream(params).try_collect().await {
+                    Ok(markets) => poller.update(markets),
+                    Err(e) => return Some((Err(e), poller)),
+                }
+            }
+        })
+        .boxed()
     }
 
     /// Get multiple markets by their ticker IDs in a single request.
@@ -306,16 +1004,11 @@ impl DflowPredictionApiClient {
         &self,
         tickers: &[String],
     ) -> Result<MarketsResponse> {
-        #[derive(serde::Serialize)]
-        struct BatchRequest {
-            tickers: Vec<String>,
-        }
-
-        self.post(
+        self.batch_op(
+            "get_markets_batch",
             "/api/v1/markets/batch",
-            &BatchRequest {
-                tickers: tickers.to_vec(),
-            },
+            "tickers",
+            tickers,
         )
         .await
     }
@@ -343,41 +1036,57 @@ impl DflowPredictionApiClient {
             params.min_close_ts.map(|v| v.to_string()),
         )]);
 
-        self.get(&format!("/api/v1/outcome_mints{}", query)).await
+        self.get_op(
+            "get_outcome_mints",
+            &format!("/api/v1/outcome_mints{}", query),
+        )
+        .await
     }
 
     /// Filter and validate a list of token addresses.
     ///
-    /// Accepts a list of token addresses (max 200) and returns only those
-    /// that are outcome mints (yes_mint or no_mint) from supported markets.
+    /// Returns only the addresses that are outcome mints (yes_mint or
+    /// no_mint) from supported markets. The API accepts at most
+    /// `MAX_FILTER_ADDRESSES` addresses per request, so longer lists are
+    /// split into chunks that are filtered concurrently (at most
+    /// `DEFAULT_CONCURRENCY` requests in flight).
     ///
     /// # Arguments
     ///
-    /// * `addresses` - List of token addresses to filter (max 200)
+    /// * `addresses` - List of token addresses to filter, of any length
     ///
     /// # Returns
     ///
-    /// Filtered list of addresses that are outcome mints.
+    /// Filtered list of addresses that are outcome mints, in the order of
+    /// the chunks, or the first error of any chunk.
     pub async fn filter_outcome_mints(
         &self,
         addresses: &[String],
     ) -> Result<FilterOutcomeMintsResponse> {
-        #[derive(serde::Serialize)]
-        struct FilterRequest {
-            addresses: Vec<String>,
+        if addresses.len() <= MAX_FILTER_ADDRESSES {
+            return self.filter_outcome_mints_chunk(addresses).await;
         }
 
-        self.post(
-            "/api/v1/filter_outcome_mints",
-            &FilterRequest {
-                addresses: addresses.to_vec(),
-            },
+        let results = fetch_concurrent(
+            addresses.chunks(MAX_FILTER_ADDRESSES),
+            DEFAULT_CONCURRENCY,
+            |chunk| self.filter_outcome_mints_chunk(chunk),
         )
-        .await
+        .await;
+
+        let mut outcome_mints = Vec::new();
+        for (_, result) in results {
+            outcome_mints.extend(result?.outcome_mints);
+        }
+        Ok(FilterOutcomeMintsResponse { outcome_mints })
     }
 
     /// Get candlestick data for a market.
     ///
+    /// The API doesn't document conditional requests (`ETag` /
+    /// `If-None-Match`), so the full history is downloaded on every call.
+    /// Cache candlesticks of closed markets on the caller side.
+    ///
     /// # Arguments
     ///
     /// * `ticker` - Market ticker
@@ -398,12 +1107,15 @@ impl DflowPredictionApiClient {
             ("endTs", params.end_ts.map(|v| v.to_string())),
             (
                 "periodInterval",
-                params.period_interval.map(|v| v.to_string()),
+                params.period_interval.map(|v| v.as_i32().to_string()),
             ),
         ]);
 
-        self.get(&format!("/api/v1/market/{ticker}/candlesticks{}", query))
-            .await
+        self.get_op(
+            "get_market_candlesticks",
+            &format!("/api/v1/market/{ticker}/candlesticks{}", query),
+        )
+        .await
     }
 
     /// Get candlestick data for a market by mint address.
@@ -428,14 +1140,14 @@ impl DflowPredictionApiClient {
             ("endTs", params.end_ts.map(|v| v.to_string())),
             (
                 "periodInterval",
-                params.period_interval.map(|v| v.to_string()),
+                params.period_interval.map(|v| v.as_i32().to_string()),
             ),
         ]);
 
-        self.get(&format!(
-            "/api/v1/market/by-mint/{mint}/candlesticks{}",
-            query
-        ))
+        self.get_op(
+            "get_market_candlesticks_by_mint",
+            &format!("/api/v1/market/by-mint/{mint}/candlesticks{}", query),
+        )
         .await
     }
 
@@ -456,8 +1168,11 @@ impl DflowPredictionApiClient {
         &self,
         market_ticker: &str,
     ) -> Result<Orderbook> {
-        self.get(&format!("/api/v1/orderbook/{}", market_ticker))
-            .await
+        self.get_op(
+            "get_orderbook",
+            &format!("/api/v1/orderbook/{}", market_ticker),
+        )
+        .await
     }
 
     /// Get orderbook data for a market by mint address.
@@ -470,8 +1185,58 @@ impl DflowPredictionApiClient {
     ///
     /// Orderbook data for the market associated with the mint.
     pub async fn get_orderbook_by_mint(&self, mint: &str) -> Result<Orderbook> {
-        self.get(&format!("/api/v1/orderbook/by-mint/{}", mint))
-            .await
+        self.get_op(
+            "get_orderbook_by_mint",
+            &format!("/api/v1/orderbook/by-mint/{}", mint),
+        )
+        .await
+    }
+
+    /// Get the orderbooks of all markets in an event.
+    ///
+    /// Loads the event with its nested markets and fetches each market's
+    /// orderbook concurrently (at most [`DEFAULT_CONCURRENCY`] requests in
+    /// flight). Markets without an orderbook map to an empty `Orderbook`.
+    ///
+    /// # Arguments
+    ///
+    /// * `event_ticker` - Event ticker ID
+    ///
+    /// # Returns
+    ///
+    /// Orderbooks keyed by market ticker.
+    pub async fn get_event_depth(
+        &self,
+        event_ticker: &str,
+    ) -> Result<HashMap<String, Orderbook>> {
+        let event = self.get_event(event_ticker, Some(true)).await?;
+        let tickers = event
+            .markets
+            .unwrap_or_default()
+            .into_iter()
+            .map(|market| market.ticker);
+
+        let results = fetch_concurrent(
+            tickers,
+            DEFAULT_CONCURRENCY,
+            |ticker| async move { self.get_orderbook(&ticker).await },
+        )
+        .await;
+
+        let mut depth = HashMap::new();
+        for (ticker, result) in results {
+            let orderbook = match result {
+                Ok(orderbook) => orderbook,
+                Err(DflowPredictionApiError::NotFound(_)) => Orderbook {
+                    ticker: ticker.clone(),
+                    ..Default::default()
+                },
+                Err(e) => return Err(e),
+            };
+            depth.insert(ticker, orderbook);
+        }
+
+        Ok(depth)
     }
 
     // =========================================================================
@@ -486,12 +1251,40 @@ impl DflowPredictionApiClient {
     ///
     /// # Returns
     ///
-    /// A paginated list of trades.
+    /// A paginated list of trades, or `InvalidParameter` if `limit` is
+    /// outside 1 to [`MAX_TRADES_LIMIT`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dflow_api_client::prediction::{
+    ///     DflowPredictionApiClient, DflowPredictionApiError, GetTradesParams,
+    /// };
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let client =
+    ///     DflowPredictionApiClient::with_default_url("api-key".to_string());
+    ///
+    /// // Rejected before any request is made
+    /// let result = client
+    ///     .get_trades(Some(GetTradesParams {
+    ///         limit: Some(100_000),
+    ///         ..Default::default()
+    ///     }))
+    ///     .await;
+    /// assert!(matches!(
+    ///     result,
+    ///     Err(DflowPredictionApiError::InvalidParameter(_))
+    /// ));
+    /// # }
+    /// ```
     pub async fn get_trades(
         &self,
         params: Option<GetTradesParams>,
     ) -> Result<TradesResponse> {
         let params = params.unwrap_or_default();
+        params.validate()?;
 
         let query = build_query_string(&[
             ("limit", params.limit.map(|v| v.to_string())),
@@ -501,7 +1294,133 @@ impl DflowPredictionApiClient {
             ("maxTs", params.max_ts.map(|v| v.to_string())),
         ]);
 
-        self.get(&format!("/api/v1/trades{}", query)).await
+        self.get_op("get_trades", &format!("/api/v1/trades{}", query))
+            .await
+    }
+
+    /// Stream all trades matching the filters, following pagination cursors.
+    ///
+    /// Fetches pages with `get_trades`, passing each returned cursor back
+    /// with the original `ticker`, `min_ts` and `max_ts` filters, and yields
+    /// the trades one by one in the order the API returns them (newest
+    /// first). The stream ends when a page has no cursor or no trades. If a
+    /// page fails, its error is yielded as the last item.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Filters and page size applied to every page
+    ///
+    /// Consumed like [`events_stream`](Self::events_stream).
+    pub fn trades_stream(
+        &self,
+        params: GetTradesParams,
+    ) -> BoxStream<'_, Result<Trade>> {
+        follow_pages(move |cursor| {
+            let mut params = params.clone();
+            if let Some(cursor) = cursor {
+                params.cursor = cursor.as_token().map(str::to_string);
+            }
+            self.get_trades(Some(params))
+        })
+    }
+
+    /// Get the complete trade history of a market since a point in time.
+    ///
+    /// Walks every page with [`trades_stream`](Self::trades_stream) using
+    /// the largest page size, drops trades repeated across pages (by trade
+    /// ID) and returns them oldest first, so the result can be appended to
+    /// a stored history and followed up with a later `min_ts`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ticker` - Market ticker
+    /// * `min_ts` - Only trades after this Unix timestamp (seconds)
+    ///
+    /// # Returns
+    ///
+    /// The trades, sorted by creation time (oldest first).
+    pub async fn trades_since(
+        &self,
+        ticker: &str,
+        min_ts: i64,
+    ) -> Result<Vec<Trade>> {
+        let mut pages = self.trades_stream(GetTradesParams {
+            limit: Some(MAX_TRADES_LIMIT),
+            ticker: Some(ticker.to_string()),
+            min_ts: Some(min_ts),
+            ..Default::default()
+        });
+
+        let mut seen = HashSet::new();
+        let mut trades = Vec::new();
+        while let Some(trade) = pages.next().await {
+            let trade = trade?;
+            if seen.insert(trade.trade_id.clone()) {
+                trades.push(trade);
+            }
+        }
+
+        trades.sort_by_key(|trade| trade.created_time);
+        Ok(trades)
+    }
+
+    /// Get all trades for a market within a time window.
+    ///
+    /// Follows the trades cursor page by page, but stops as soon as a page
+    /// reaches trades older than `start_ts` instead of walking the entire
+    /// trade history. Trades outside the window on the boundary page are
+    /// trimmed.
+    ///
+    /// # Arguments
+    ///
+    /// * `ticker` - Market ticker
+    /// * `start_ts` - Start of the window (Unix timestamp in seconds, inclusive)
+    /// * `end_ts` - End of the window (Unix timestamp in seconds, inclusive)
+    ///
+    /// # Returns
+    ///
+    /// The trades in the window, sorted by creation time (oldest first).
+    pub async fn trades_in_range(
+        &self,
+        ticker: &str,
+        start_ts: i64,
+        end_ts: i64,
+    ) -> Result<Vec<Trade>> {
+        let in_window = |trade: &Trade| {
+            let ts = trade.created_time / 1000;
+            ts >= start_ts && ts <= end_ts
+        };
+
+        let mut trades = Vec::new();
+        let mut cursor = None;
+
+        loop {
+            let response = self
+                .get_trades(Some(GetTradesParams {
+                    limit: Some(MAX_TRADES_LIMIT),
+                    cursor,
+                    ticker: Some(ticker.to_string()),
+                    min_ts: Some(start_ts),
+                    max_ts: Some(end_ts),
+                }))
+                .await?;
+
+            let crossed_start = response
+                .trades
+                .iter()
+                .any(|trade| trade.created_time / 1000 < start_ts);
+            let page_empty = response.trades.is_empty();
+
+            trades.extend(response.trades.into_iter().filter(in_window));
+
+            cursor = response.cursor;
+            if crossed_start || page_empty || cursor.is_none() {
+                break;
+            }
+        }
+
+        trades.sort_by_key(|trade| trade.created_time);
+        Ok(trades)
     }
 
     /// Get trades for a market by mint address.
@@ -513,13 +1432,15 @@ impl DflowPredictionApiClient {
     ///
     /// # Returns
     ///
-    /// A list of trades for the market associated with the mint.
+    /// A list of trades for the market associated with the mint, or
+    /// `InvalidParameter` if `limit` is outside 1 to [`MAX_TRADES_LIMIT`].
     pub async fn get_trades_by_mint(
         &self,
         mint: &str,
         params: Option<GetTradesParams>,
     ) -> Result<TradesResponse> {
         let params = params.unwrap_or_default();
+        params.validate()?;
 
         let query = build_query_string(&[
             ("limit", params.limit.map(|v| v.to_string())),
@@ -528,8 +1449,11 @@ impl DflowPredictionApiClient {
             ("maxTs", params.max_ts.map(|v| v.to_string())),
         ]);
 
-        self.get(&format!("/api/v1/trades/by-mint/{}{}", mint, query))
-            .await
+        self.get_op(
+            "get_trades_by_mint",
+            &format!("/api/v1/trades/by-mint/{}{}", mint, query),
+        )
+        .await
     }
 
     // =========================================================================
@@ -538,23 +1462,41 @@ impl DflowPredictionApiClient {
 
     /// Get live data for specified milestones.
     ///
-    /// Relays live data from the Kalshi API for one or more milestones.
+    /// Relays live data from the Kalshi API for one or more milestones. The
+    /// API accepts at most `MAX_LIVE_DATA_MILESTONES` IDs per request, so
+    /// longer lists are split into chunks that are fetched concurrently (at
+    /// most `DEFAULT_CONCURRENCY` requests in flight) and combined with
+    /// [`LiveDataResponse::merge`] in chunk order.
     ///
     /// # Arguments
     ///
-    /// * `milestone_ids` - Array of milestone IDs (max 100)
+    /// * `milestone_ids` - Array of milestone IDs, of any length
     ///
     /// # Returns
     ///
-    /// Live data for the requested milestones.
+    /// Live data for the requested milestones, or the first error of any
+    /// chunk.
     pub async fn get_live_data(
         &self,
         milestone_ids: &[String],
     ) -> Result<LiveDataResponse> {
-        let ids_param = milestone_ids.join(",");
-        let query = build_query_string(&[("milestoneIds", Some(ids_param))]);
+        if milestone_ids.len() <= MAX_LIVE_DATA_MILESTONES {
+            return self.get_live_data_chunk(milestone_ids).await;
+        }
+
+        let results = fetch_concurrent(
+            milestone_ids.chunks(MAX_LIVE_DATA_MILESTONES),
+            DEFAULT_CONCURRENCY,
+            |chunk| self.get_live_data_chunk(chunk),
+        )
+        .await;
 
-        self.get(&format!("/api/v1/live_data{}", query)).await
+        let mut results = results.into_iter().map(|(_, result)| result);
+        let mut live_data = results.next().expect("at least two chunks")?;
+        for result in results {
+            live_data.merge(result?);
+        }
+        Ok(live_data)
     }
 
     /// Get live data for an event by its ticker.
@@ -570,8 +1512,11 @@ impl DflowPredictionApiClient {
         &self,
         event_ticker: &str,
     ) -> Result<LiveDataResponse> {
-        self.get(&format!("/api/v1/live_data/by-event/{}", event_ticker))
-            .await
+        self.get_op(
+            "get_live_data_by_event",
+            &format!("/api/v1/live_data/by-event/{}", event_ticker),
+        )
+        .await
     }
 
     /// Get live data for a market by mint address.
@@ -587,8 +1532,48 @@ impl DflowPredictionApiClient {
         &self,
         mint: &str,
     ) -> Result<LiveDataResponse> {
-        self.get(&format!("/api/v1/live_data/by-mint/{}", mint))
-            .await
+        self.get_op(
+            "get_live_data_by_mint",
+            &format!("/api/v1/live_data/by-mint/{}", mint),
+        )
+        .await
+    }
+
+    /// Get live data for multiple markets by mint address.
+    ///
+    /// Calls `get_live_data_by_mint` for each mint concurrently (at most
+    /// [`DEFAULT_CONCURRENCY`] requests in flight). Mints without live data
+    /// are left out of the result instead of failing the whole batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `mints` - Mint addresses (yes or no outcome mints)
+    ///
+    /// # Returns
+    ///
+    /// Live data keyed by mint address.
+    pub async fn get_live_data_for_mints(
+        &self,
+        mints: &[String],
+    ) -> Result<HashMap<String, LiveDataResponse>> {
+        let results =
+            fetch_concurrent(mints.iter(), DEFAULT_CONCURRENCY, |mint| {
+                self.get_live_data_by_mint(mint)
+            })
+            .await;
+
+        let mut live_data = HashMap::new();
+        for (mint, result) in results {
+            match result {
+                Ok(response) if !response.is_empty() => {
+                    live_data.insert(mint.clone(), response);
+                }
+                Ok(_) | Err(DflowPredictionApiError::NotFound(_)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(live_data)
     }
 
     // =========================================================================
@@ -622,7 +1607,27 @@ impl DflowPredictionApiClient {
             ("status", params.status.map(|v| v.as_str().to_string())),
         ]);
 
-        self.get(&format!("/api/v1/series{}", query)).await
+        self.get_op("get_series", &format!("/api/v1/series{}", query))
+            .await
+    }
+
+    /// Get series templates grouped by category.
+    ///
+    /// Same as [`get_series`](Self::get_series), with the result bucketed
+    /// by `Series::category`, e.g. for category navigation.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Query parameters for filtering
+    ///
+    /// # Returns
+    ///
+    /// The series of every category, in response order.
+    pub async fn get_series_grouped(
+        &self,
+        params: Option<GetSeriesParams>,
+    ) -> Result<HashMap<String, Vec<Series>>> {
+        Ok(self.get_series(params).await?.group_by_category())
     }
 
     /// Get a single series by its ticker.
@@ -638,7 +1643,11 @@ impl DflowPredictionApiClient {
         &self,
         series_ticker: &str,
     ) -> Result<Series> {
-        self.get(&format!("/api/v1/series/{}", series_ticker)).await
+        self.get_op(
+            "get_series_by_ticker",
+            &format!("/api/v1/series/{}", series_ticker),
+        )
+        .await
     }
 
     // =========================================================================
@@ -655,7 +1664,27 @@ impl DflowPredictionApiClient {
     pub async fn get_tags_by_categories(
         &self,
     ) -> Result<TagsByCategoriesResponse> {
-        self.get("/api/v1/tags_by_categories").await
+        self.get_op("get_tags_by_categories", "/api/v1/tags_by_categories")
+            .await
+    }
+
+    /// Get the tags of a single series category.
+    ///
+    /// Fetches the full category map and picks out one category.
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - Series category, e.g. "Sports"
+    ///
+    /// # Returns
+    ///
+    /// The category's tags, or an empty list if the category doesn't exist.
+    pub async fn get_tags_for_category(
+        &self,
+        category: impl AsRef<str>,
+    ) -> Result<Vec<String>> {
+        let response = self.get_tags_by_categories().await?;
+        Ok(response.tags_for(category.as_ref()).to_vec())
     }
 
     // =========================================================================
@@ -672,7 +1701,8 @@ impl DflowPredictionApiClient {
     pub async fn get_filters_by_sports(
         &self,
     ) -> Result<FiltersBySportsResponse> {
-        self.get("/api/v1/filters_by_sports").await
+        self.get_op("get_filters_by_sports", "/api/v1/filters_by_sports")
+            .await
     }
 
     // =========================================================================
@@ -689,11 +1719,36 @@ impl DflowPredictionApiClient {
     ///
     /// # Returns
     ///
-    /// Matching events with optional nested markets.
+    /// Matching events with optional nested markets, or `InvalidParameter`
+    /// if the params fail [`SearchParams::validate`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use dflow_api_client::prediction::{
+    ///     DflowPredictionApiClient, SearchParams,
+    /// };
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client =
+    ///     DflowPredictionApiClient::with_default_url("api-key".to_string());
+    ///
+    /// let results = client
+    ///     .search_events(SearchParams {
+    ///         q: "election".to_string(),
+    ///         with_nested_markets: Some(true),
+    ///         ..Default::default()
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub async fn search_events(
         &self,
         params: SearchParams,
     ) -> Result<SearchResponse> {
+        params.validate()?;
+
         let query = build_query_string(&[
             ("q", Some(params.q)),
             ("sort", params.sort.map(|v| v.as_str().to_string())),
@@ -710,6 +1765,86 @@ impl DflowPredictionApiClient {
             ),
         ]);
 
-        self.get(&format!("/api/v1/search{}", query)).await
+        self.get_op("search_events", &format!("/api/v1/search{}", query))
+            .await
+    }
+
+    // =========================================================================
+    // Internal Methods
+    // =========================================================================
+
+    /// Get live data for at most `MAX_LIVE_DATA_MILESTONES` milestones in
+    /// one request.
+    async fn get_live_data_chunk(
+        &self,
+        milestone_ids: &[String],
+    ) -> Result<LiveDataResponse> {
+        let ids_param = milestone_ids.join(",");
+        let query = build_query_string(&[("milestoneIds", Some(ids_param))]);
+
+        self.get_op("get_live_data", &format!("/api/v1/live_data{}", query))
+            .await
+    }
+
+    /// Filter at most `MAX_FILTER_ADDRESSES` addresses in one request.
+    async fn filter_outcome_mints_chunk(
+        &self,
+        addresses: &[String],
+    ) -> Result<FilterOutcomeMintsResponse> {
+        self.batch_op(
+            "filter_outcome_mints",
+            "/api/v1/filter_outcome_mints",
+            "addresses",
+            addresses,
+        )
+        .await
+    }
+
+    /// Look up a list of values on a batch endpoint.
+    ///
+    /// Sends a POST with `{ "<key>": [values] }`.
+    async fn batch_op<T: serde::de::DeserializeOwned>(
+        &self,
+        operation: &str,
+        endpoint: &str,
+        key: &str,
+        values: &[String],
+    ) -> Result<T> {
+        let body = serde_json::json!({ key: values });
+        self.post_op(operation, endpoint, &body).await
+    }
+}
+
+/// State for diffing polled markets.
+struct MarketsPoller {
+    params: Option<GetMarketsParams>,
+    fields: Vec<MarketField>,
+    timer: Interval,
+    /// Latest known state of every market, by ticker
+    snapshot: HashMap<String, Market>,
+    /// Changes found by the last poll, waiting to be emitted
+    ready: VecDeque<MarketChange>,
+}
+
+impl MarketsPoller {
+    /// Record a poll, queueing changes against the previous snapshot.
+    fn update(&mut self, markets: Vec<Market>) {
+        for market in markets {
+            if let Some(previous) = self.snapshot.get(&market.ticker) {
+                for field in &self.fields {
+                    let old = field.value(previous);
+                    let new = field.value(&market);
+                    if old != new {
+                        self.ready.push_back(MarketChange {
+                            ticker: market.ticker.clone(),
+                            field: *field,
+                            old,
+                            new,
+                        });
+                    }
+                }
+            }
+            self.snapshot.insert(market.ticker.clone(), market);
+        }
     }
 }
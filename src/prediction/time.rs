@@ -0,0 +1,102 @@
+//! `chrono` accessors for timestamp fields.
+//!
+//! These are only available with the `chrono` feature enabled. Timestamps on
+//! response types are Unix milliseconds, while the candlestick and trade
+//! query parameters take Unix seconds; the helpers here do the conversion in
+//! both directions so the units never have to be remembered.
+
+use chrono::{DateTime, Utc};
+
+use super::types::{
+    Candlestick, ForecastPercentile, GetCandlesticksParams, Market, Trade,
+};
+
+/// Convert a Unix timestamp in milliseconds to a `DateTime<Utc>`.
+///
+/// Out of range timestamps map to the Unix epoch.
+pub(crate) fn datetime_from_millis(millis: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp_millis(millis).unwrap_or_default()
+}
+
+impl Market {
+    /// Market open time (`open_time`, milliseconds).
+    pub fn open_datetime(&self) -> DateTime<Utc> {
+        datetime_from_millis(self.open_time)
+    }
+
+    /// Market close time (`close_time`, milliseconds).
+    pub fn close_datetime(&self) -> DateTime<Utc> {
+        datetime_from_millis(self.close_time)
+    }
+
+    /// Market expiration time (`expiration_time`, milliseconds).
+    pub fn expiration_datetime(&self) -> DateTime<Utc> {
+        datetime_from_millis(self.expiration_time)
+    }
+}
+
+impl Candlestick {
+    /// Candle start time (`time`, milliseconds).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dflow_api_client::prediction::Candlestick;
+    ///
+    /// let candle = Candlestick {
+    ///     time: 1_700_000_000_000,
+    ///     open: 0.5,
+    ///     high: 0.6,
+    ///     low: 0.4,
+    ///     close: 0.55,
+    ///     volume: None,
+    /// };
+    ///
+    /// assert_eq!(candle.datetime().timestamp(), 1_700_000_000);
+    /// ```
+    pub fn datetime(&self) -> DateTime<Utc> {
+        datetime_from_millis(self.time)
+    }
+}
+
+impl ForecastPercentile {
+    /// Data point time (`time`, milliseconds).
+    pub fn datetime(&self) -> DateTime<Utc> {
+        datetime_from_millis(self.time)
+    }
+}
+
+impl Trade {
+    /// Trade creation time (`created_time`, milliseconds).
+    pub fn created_datetime(&self) -> DateTime<Utc> {
+        datetime_from_millis(self.created_time)
+    }
+}
+
+impl GetCandlesticksParams {
+    /// Set the time range, converting to the Unix seconds the candlestick
+    /// endpoints expect.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use dflow_api_client::prediction::GetCandlesticksParams;
+    ///
+    /// let start = Utc.with_ymd_and_hms(2024, 11, 5, 0, 0, 0).unwrap();
+    /// let end = Utc.with_ymd_and_hms(2024, 11, 6, 0, 0, 0).unwrap();
+    ///
+    /// let params = GetCandlesticksParams::default().with_range(start, end);
+    /// assert_eq!(params.start_ts, Some(1_730_764_800));
+    /// assert_eq!(params.end_ts, Some(1_730_851_200));
+    /// ```
+    pub fn with_range(
+        mut self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Self {
+        self.start_ts = Some(start.timestamp());
+        self.end_ts = Some(end.timestamp());
+        self
+    }
+}
@@ -1,5 +1,9 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
+use crate::common::{Cursor, DflowApiError, Paginated, Result as ApiResult};
+
 // =============================================================================
 // Common Types
 // =============================================================================
@@ -31,6 +35,34 @@ pub struct MarketAccounts {
 // =============================================================================
 
 /// A prediction market
+///
+/// # Example
+///
+/// ```
+/// use dflow_api_client::prediction::Market;
+///
+/// let market: Market = serde_json::from_value(serde_json::json!({
+///     "ticker": "T", "title": "", "subtitle": "", "eventTicker": "E",
+///     "marketType": "binary", "status": "active", "result": "",
+///     "canCloseEarly": true, "openTime": 1000, "closeTime": 5000,
+///     "expirationTime": 6000, "volume": 0, "openInterest": 0,
+///     "rulesPrimary": "", "yesSubTitle": "", "noSubTitle": "",
+///     "accounts": {},
+///     "earlyCloseCondition": "Resolves once the outcome is known",
+///     "yesAsk": "0.4200", "yesBid": "", "noAsk": "0",
+/// }))
+/// .unwrap();
+///
+/// assert_eq!(
+///     market.early_close(),
+///     Some("Resolves once the outcome is known")
+/// );
+/// assert!(market.may_settle_before(3000));
+/// assert_eq!(market.yes_ask_f64(), Some(0.42));
+/// // Empty prices are `None`, zero quotes only after clearing them
+/// assert_eq!(market.yes_bid, None);
+/// assert_eq!(market.zero_quotes_as_none().no_ask, None);
+/// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Market {
@@ -42,12 +74,12 @@ pub struct Market {
     pub subtitle: String,
     /// Event ticker this market belongs to
     pub event_ticker: String,
-    /// Market type (e.g., "binary")
-    pub market_type: String,
+    /// Market type (e.g., binary)
+    pub market_type: MarketType,
     /// Market status (e.g., "active", "closed", "determined")
-    pub status: String,
-    /// Market result (e.g., "yes", "no", or empty if not determined)
-    pub result: String,
+    pub status: MarketStatus,
+    /// Market result (yes, no, or undetermined)
+    pub result: MarketResult,
     /// Whether the market can close early
     pub can_close_early: bool,
     /// Market open time (Unix timestamp in milliseconds)
@@ -74,20 +106,216 @@ pub struct Market {
     /// Early close condition description (optional)
     #[serde(default)]
     pub early_close_condition: Option<String>,
-    /// Best yes ask price (optional)
-    #[serde(default)]
+    /// Best yes ask price (`None` if there is no quote)
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub yes_ask: Option<String>,
-    /// Best yes bid price (optional)
-    #[serde(default)]
+    /// Best yes bid price (`None` if there is no quote)
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub yes_bid: Option<String>,
-    /// Best no ask price (optional)
-    #[serde(default)]
+    /// Best no ask price (`None` if there is no quote)
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub no_ask: Option<String>,
-    /// Best no bid price (optional)
-    #[serde(default)]
+    /// Best no bid price (`None` if there is no quote)
+    #[serde(default, deserialize_with = "empty_as_none")]
     pub no_bid: Option<String>,
 }
 
+impl Market {
+    /// Early close condition, if the market can close early.
+    ///
+    /// # Returns
+    ///
+    /// The early close condition when `can_close_early` is true, otherwise
+    /// `None` (even if a condition is present).
+    ///
+    /// See the [`Market`] example.
+    pub fn early_close(&self) -> Option<&str> {
+        if self.can_close_early {
+            self.early_close_condition.as_deref()
+        } else {
+            None
+        }
+    }
+
+    /// Whether the market may settle before the given time.
+    ///
+    /// True if the market is scheduled to close at or before `time`, or if it
+    /// can close early and `time` is after it opened.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - Unix timestamp in milliseconds
+    ///
+    /// See the [`Market`] example.
+    pub fn may_settle_before(&self, time: i64) -> bool {
+        self.close_time <= time
+            || (self.can_close_early && time > self.open_time)
+    }
+
+    /// Treat zero quotes as missing.
+    ///
+    /// Empty price strings always deserialize to `None`. Some backends also
+    /// report a missing quote as `"0"`; this clears such prices so `None`
+    /// consistently means "no quote". Not applied automatically, since a
+    /// quote of 0 can be legitimate.
+    ///
+    /// See the [`Market`] example.
+    pub fn zero_quotes_as_none(mut self) -> Self {
+        for quote in [
+            &mut self.yes_ask,
+            &mut self.yes_bid,
+            &mut self.no_ask,
+            &mut self.no_bid,
+        ] {
+            if quote
+                .as_deref()
+                .and_then(|price| price.parse::<f64>().ok())
+                .is_some_and(|price| price == 0.0)
+            {
+                *quote = None;
+            }
+        }
+        self
+    }
+
+    /// YES ask as a number, or `None` if missing or malformed.
+    pub fn yes_ask_f64(&self) -> Option<f64> {
+        self.try_yes_ask().ok().flatten()
+    }
+
+    /// YES ask as a number.
+    ///
+    /// Returns `Ok(None)` if there is no quote and an error if the price
+    /// string is malformed.
+    pub fn try_yes_ask(&self) -> ApiResult<Option<f64>> {
+        self.yes_ask
+            .as_deref()
+            .map(|price| parse_price("yes_ask", price))
+            .transpose()
+    }
+
+    /// YES bid as a number, or `None` if missing or malformed.
+    pub fn yes_bid_f64(&self) -> Option<f64> {
+        self.try_yes_bid().ok().flatten()
+    }
+
+    /// YES bid as a number.
+    ///
+    /// Returns `Ok(None)` if there is no quote and an error if the price
+    /// string is malformed.
+    pub fn try_yes_bid(&self) -> ApiResult<Option<f64>> {
+        self.yes_bid
+            .as_deref()
+            .map(|price| parse_price("yes_bid", price))
+            .transpose()
+    }
+
+    /// NO ask as a number, or `None` if missing or malformed.
+    pub fn no_ask_f64(&self) -> Option<f64> {
+        self.try_no_ask().ok().flatten()
+    }
+
+    /// NO ask as a number.
+    ///
+    /// Returns `Ok(None)` if there is no quote and an error if the price
+    /// string is malformed.
+    pub fn try_no_ask(&self) -> ApiResult<Option<f64>> {
+        self.no_ask
+            .as_deref()
+            .map(|price| parse_price("no_ask", price))
+            .transpose()
+    }
+
+    /// NO bid as a number, or `None` if missing or malformed.
+    pub fn no_bid_f64(&self) -> Option<f64> {
+        self.try_no_bid().ok().flatten()
+    }
+
+    /// NO bid as a number.
+    ///
+    /// Returns `Ok(None)` if there is no quote and an error if the price
+    /// string is malformed.
+    pub fn try_no_bid(&self) -> ApiResult<Option<f64>> {
+        self.no_bid
+            .as_deref()
+            .map(|price| parse_price("no_bid", price))
+            .transpose()
+    }
+}
+
+/// Parse a price string, naming the field in the error.
+fn parse_price(field: &str, price: &str) -> ApiResult<f64> {
+    price.trim().parse().map_err(|_| {
+        DflowApiError::InvalidData(format!("invalid {}: {:?}", field, price))
+    })
+}
+
+/// Deserialize an optional string, treating empty strings as `None`.
+fn empty_as_none<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value = Option::<String>::deserialize(deserializer)?;
+    Ok(value.filter(|value| !value.trim().is_empty()))
+}
+
+/// A market field watched by `markets_changes_stream`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MarketField {
+    Status,
+    Result,
+    CloseTime,
+    Volume,
+    OpenInterest,
+    YesBid,
+    YesAsk,
+    NoBid,
+    NoAsk,
+}
+
+impl MarketField {
+    /// Every watchable field.
+    pub const ALL: [MarketField; 9] = [
+        MarketField::Status,
+        MarketField::Result,
+        MarketField::CloseTime,
+        MarketField::Volume,
+        MarketField::OpenInterest,
+        MarketField::YesBid,
+        MarketField::YesAsk,
+        MarketField::NoBid,
+        MarketField::NoAsk,
+    ];
+
+    /// The value of this field in a market, formatted as a string.
+    pub fn value(&self, market: &Market) -> Option<String> {
+        match self {
+            MarketField::Status => Some(market.status.as_str().to_string()),
+            MarketField::Result => Some(market.result.as_str().to_string()),
+            MarketField::CloseTime => Some(market.close_time.to_string()),
+            MarketField::Volume => Some(market.volume.to_string()),
+            MarketField::OpenInterest => Some(market.open_interest.to_string()),
+            MarketField::YesBid => market.yes_bid.clone(),
+            MarketField::YesAsk => market.yes_ask.clone(),
+            MarketField::NoBid => market.no_bid.clone(),
+            MarketField::NoAsk => market.no_ask.clone(),
+        }
+    }
+}
+
+/// A change of a market field between two polls.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarketChange {
+    /// Market ticker ID
+    pub ticker: String,
+    /// Field that changed
+    pub field: MarketField,
+    /// Previous value
+    pub old: Option<String>,
+    /// New value
+    pub new: Option<String>,
+}
+
 // =============================================================================
 // Event Types
 // =============================================================================
@@ -162,6 +390,80 @@ pub struct Candlestick {
     pub volume: Option<i64>,
 }
 
+/// Fill missing intervals of a candlestick series with flat candles.
+///
+/// The API leaves out intervals without trades, which charting libraries
+/// expecting evenly spaced bars don't handle. Between the first and the
+/// last candle, every missing slot gets a candle with open, high, low and
+/// close at the previous close and a volume of 0.
+///
+/// # Arguments
+///
+/// * `candles` - Candlesticks, in any order
+/// * `period_interval_minutes` - Interval the candles were requested with
+///   (1, 60 or 1440, see [`PeriodInterval`])
+///
+/// # Returns
+///
+/// The candles sorted by time with the gaps filled, or just sorted if the
+/// interval isn't positive.
+///
+/// # Example
+///
+/// ```
+/// use dflow_api_client::prediction::{Candlestick, PeriodInterval, fill_gaps};
+///
+/// let candle = |time: i64, close: f64| Candlestick {
+///     time,
+///     open: close,
+///     high: close,
+///     low: close,
+///     close,
+///     volume: Some(10),
+/// };
+///
+/// // Hourly candles with 02:00 and 03:00 missing
+/// let hour = 3_600_000;
+/// let candles = [candle(0, 0.40), candle(hour, 0.45), candle(4 * hour, 0.50)];
+///
+/// let filled = fill_gaps(&candles, PeriodInterval::OneHour.as_i32().into());
+/// let times: Vec<i64> = filled.iter().map(|c| c.time / hour).collect();
+/// assert_eq!(times, [0, 1, 2, 3, 4]);
+/// assert_eq!(filled[3].close, 0.45);
+/// ```
+pub fn fill_gaps(
+    candles: &[Candlestick],
+    period_interval_minutes: i64,
+) -> Vec<Candlestick> {
+    let mut sorted = candles.to_vec();
+    sorted.sort_by_key(|candle| candle.time);
+    if period_interval_minutes <= 0 {
+        return sorted;
+    }
+
+    let step = period_interval_minutes * 60_000;
+    let mut filled: Vec<Candlestick> = Vec::with_capacity(sorted.len());
+    for candle in sorted {
+        if let Some(previous) = filled.last() {
+            let close = previous.close;
+            let mut time = previous.time + step;
+            while time < candle.time {
+                filled.push(Candlestick {
+                    time,
+                    open: close,
+                    high: close,
+                    low: close,
+                    close,
+                    volume: Some(0),
+                });
+                time += step;
+            }
+        }
+        filled.push(candle);
+    }
+    filled
+}
+
 // =============================================================================
 // Forecast Percentile Types
 // =============================================================================
@@ -195,6 +497,18 @@ pub struct EventsResponse {
     pub cursor: Option<i32>,
 }
 
+impl Paginated for EventsResponse {
+    type Item = Event;
+
+    fn next_cursor(&self) -> Option<Cursor> {
+        self.cursor.map(Cursor::Offset)
+    }
+
+    fn items(self) -> Vec<Event> {
+        self.events
+    }
+}
+
 /// Response for get_markets endpoint
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -206,6 +520,18 @@ pub struct MarketsResponse {
     pub cursor: Option<i32>,
 }
 
+impl Paginated for MarketsResponse {
+    type Item = Market;
+
+    fn next_cursor(&self) -> Option<Cursor> {
+        self.cursor.map(Cursor::Offset)
+    }
+
+    fn items(self) -> Vec<Market> {
+        self.markets
+    }
+}
+
 /// Response for get_event_candlesticks endpoint
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -214,6 +540,81 @@ pub struct CandlesticksResponse {
     pub candlesticks: Vec<Candlestick>,
 }
 
+impl CandlesticksResponse {
+    /// Close price of the most recent candlestick.
+    ///
+    /// # Returns
+    ///
+    /// The latest close, or `None` if there are no candlesticks.
+    pub fn latest_close(&self) -> Option<f64> {
+        self.candlesticks
+            .iter()
+            .max_by_key(|candle| candle.time)
+            .map(|candle| candle.close)
+    }
+
+    /// Percentage price change over a trailing window (e.g. 24h change).
+    ///
+    /// Compares the latest close with the close of the candlestick nearest
+    /// to `now - window_secs`. Gaps in the data are tolerated as long as the
+    /// reference candlestick is within half a window of that time.
+    ///
+    /// # Arguments
+    ///
+    /// * `window_secs` - Window length in seconds (e.g. `86_400` for 24h)
+    /// * `now` - Current time (Unix timestamp in seconds)
+    ///
+    /// # Returns
+    ///
+    /// The change in percent (e.g. `3.2` for +3.2%), or `None` if there is
+    /// not enough data in the window or the reference price is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dflow_api_client::prediction::{Candlestick, CandlesticksResponse};
+    ///
+    /// const HOUR_MS: i64 = 3_600_000;
+    ///
+    /// let candle = |hour: i64, close: f64| Candlestick {
+    ///     time: hour * HOUR_MS,
+    ///     open: close,
+    ///     high: close,
+    ///     low: close,
+    ///     close,
+    ///     volume: None,
+    /// };
+    ///
+    /// let response = CandlesticksResponse {
+    ///     candlesticks: vec![candle(1, 0.50), candle(25, 0.60)],
+    /// };
+    ///
+    /// // 24h change at hour 25
+    /// let change = response.price_change_pct(24 * 3600, 25 * 3600).unwrap();
+    /// assert!((change - 20.0).abs() < 1e-9);
+    /// ```
+    pub fn price_change_pct(&self, window_secs: i64, now: i64) -> Option<f64> {
+        let latest =
+            self.candlesticks.iter().max_by_key(|candle| candle.time)?;
+
+        let target_ms = (now - window_secs) * 1000;
+        let tolerance_ms = window_secs * 1000 / 2;
+
+        let reference = self
+            .candlesticks
+            .iter()
+            .filter(|candle| candle.time < latest.time)
+            .min_by_key(|candle| (candle.time - target_ms).abs())
+            .filter(|candle| (candle.time - target_ms).abs() <= tolerance_ms)?;
+
+        if reference.close == 0.0 {
+            return None;
+        }
+
+        Some((latest.close - reference.close) / reference.close * 100.0)
+    }
+}
+
 /// Response for forecast percentile history endpoint
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -243,7 +644,7 @@ pub struct FilterOutcomeMintsResponse {
 // =============================================================================
 
 /// Sort options for events/markets queries
-#[derive(Debug, Clone, Copy, Serialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum SortField {
     Volume,
@@ -265,31 +666,190 @@ impl SortField {
     }
 }
 
-/// Market status filter options
-#[derive(Debug, Clone, Copy, Serialize)]
-#[serde(rename_all = "lowercase")]
+/// Market status, used both on markets and as a filter option
+///
+/// Serializes to and from the lowercase API strings. Statuses unknown to this
+/// client deserialize to `Other` with the original string.
+///
+/// # Example
+///
+/// ```
+/// use dflow_api_client::prediction::MarketStatus;
+///
+/// let status: MarketStatus = serde_json::from_str(r#""active""#).unwrap();
+/// assert_eq!(status, MarketStatus::Active);
+///
+/// let status: MarketStatus = serde_json::from_str(r#""paused""#).unwrap();
+/// assert_eq!(status, MarketStatus::Other("paused".to_string()));
+/// assert_eq!(serde_json::to_string(&status).unwrap(), r#""paused""#);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
 pub enum MarketStatus {
     Initialized,
     Active,
     Inactive,
     Closed,
     Determined,
+    /// A status not known to this client
+    Other(String),
 }
 
 impl MarketStatus {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             MarketStatus::Initialized => "initialized",
             MarketStatus::Active => "active",
             MarketStatus::Inactive => "inactive",
             MarketStatus::Closed => "closed",
             MarketStatus::Determined => "determined",
+            MarketStatus::Other(status) => status,
+        }
+    }
+}
+
+impl From<String> for MarketStatus {
+    fn from(status: String) -> Self {
+        match status.as_str() {
+            "initialized" => MarketStatus::Initialized,
+            "active" => MarketStatus::Active,
+            "inactive" => MarketStatus::Inactive,
+            "closed" => MarketStatus::Closed,
+            "determined" => MarketStatus::Determined,
+            _ => MarketStatus::Other(status),
+        }
+    }
+}
+
+impl From<MarketStatus> for String {
+    fn from(status: MarketStatus) -> Self {
+        match status {
+            MarketStatus::Other(status) => status,
+            status => status.as_str().to_string(),
+        }
+    }
+}
+
+/// Type of a market
+///
+/// Serializes to and from the lowercase API strings. Types unknown to this
+/// client deserialize to `Other` with the original string.
+///
+/// # Example
+///
+/// ```
+/// use dflow_api_client::prediction::MarketType;
+///
+/// let market_type: MarketType = serde_json::from_str(r#""binary""#).unwrap();
+/// assert_eq!(market_type, MarketType::Binary);
+/// assert_eq!(serde_json::to_string(&market_type).unwrap(), r#""binary""#);
+///
+/// let market_type: MarketType = serde_json::from_str(r#""range""#).unwrap();
+/// assert_eq!(market_type, MarketType::Other("range".to_string()));
+/// assert_eq!(serde_json::to_string(&market_type).unwrap(), r#""range""#);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum MarketType {
+    Binary,
+    Scalar,
+    /// A type not known to this client
+    Other(String),
+}
+
+impl MarketType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            MarketType::Binary => "binary",
+            MarketType::Scalar => "scalar",
+            MarketType::Other(market_type) => market_type,
+        }
+    }
+}
+
+impl From<String> for MarketType {
+    fn from(market_type: String) -> Self {
+        match market_type.as_str() {
+            "binary" => MarketType::Binary,
+            "scalar" => MarketType::Scalar,
+            _ => MarketType::Other(market_type),
+        }
+    }
+}
+
+impl From<MarketType> for String {
+    fn from(market_type: MarketType) -> Self {
+        match market_type {
+            MarketType::Other(market_type) => market_type,
+            market_type => market_type.as_str().to_string(),
+        }
+    }
+}
+
+/// Result of a market
+///
+/// Serializes to and from the lowercase API strings, with an empty string
+/// for an undetermined market.
+///
+/// # Example
+///
+/// ```
+/// use dflow_api_client::prediction::MarketResult;
+///
+/// let result: MarketResult = serde_json::from_str(r#""yes""#).unwrap();
+/// assert_eq!(result, MarketResult::Yes);
+///
+/// let result: MarketResult = serde_json::from_str(r#""""#).unwrap();
+/// assert_eq!(result, MarketResult::Undetermined);
+/// assert_eq!(serde_json::to_string(&result).unwrap(), r#""""#);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum MarketResult {
+    Yes,
+    No,
+    /// The market has no result yet
+    Undetermined,
+    /// A result not known to this client
+    Other(String),
+}
+
+impl MarketResult {
+    pub fn as_str(&self) -> &str {
+        match self {
+            MarketResult::Yes => "yes",
+            MarketResult::No => "no",
+            MarketResult::Undetermined => "",
+            MarketResult::Other(result) => result,
+        }
+    }
+}
+
+impl From<String> for MarketResult {
+    fn from(result: String) -> Self {
+        match result.as_str() {
+            "yes" => MarketResult::Yes,
+            "no" => MarketResult::No,
+            "" => MarketResult::Undetermined,
+            _ => MarketResult::Other(result),
+        }
+    }
+}
+
+impl From<MarketResult> for String {
+    fn from(result: MarketResult) -> Self {
+        match result {
+            MarketResult::Other(result) => result,
+            result => result.as_str().to_string(),
         }
     }
 }
 
 /// Period interval options for candlesticks (in minutes)
-#[derive(Debug, Clone, Copy)]
+///
+/// Serializes to and from the number of minutes, as sent to the API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "i32", into = "i32")]
 pub enum PeriodInterval {
     /// 1 minute
     OneMinute = 1,
@@ -305,8 +865,115 @@ impl PeriodInterval {
     }
 }
 
+impl TryFrom<i32> for PeriodInterval {
+    type Error = String;
+
+    fn try_from(minutes: i32) -> Result<Self, Self::Error> {
+        match minutes {
+            1 => Ok(PeriodInterval::OneMinute),
+            60 => Ok(PeriodInterval::OneHour),
+            1440 => Ok(PeriodInterval::OneDay),
+            _ => Err(format!("unsupported period interval: {}", minutes)),
+        }
+    }
+}
+
+impl From<PeriodInterval> for i32 {
+    fn from(interval: PeriodInterval) -> Self {
+        interval.as_i32()
+    }
+}
+
+/// Period interval options for forecast percentile history (in minutes)
+///
+/// Unlike candlesticks, the forecast endpoints also accept an interval of
+/// 0, so this is a separate type. Every [`PeriodInterval`] converts into it.
+///
+/// # Example
+///
+/// ```
+/// use dflow_api_client::prediction::{
+///     ForecastPeriodInterval, GetCandlesticksParams,
+///     GetForecastPercentileHistoryParams, PeriodInterval,
+/// };
+///
+/// let candles = GetCandlesticksParams {
+///     period_interval: Some(PeriodInterval::OneHour),
+///     ..Default::default()
+/// };
+/// let forecast = GetForecastPercentileHistoryParams {
+///     period_interval: candles.period_interval.map(Into::into),
+///     ..Default::default()
+/// };
+/// assert_eq!(forecast.period_interval, Some(ForecastPeriodInterval::OneHour));
+/// assert_eq!(ForecastPeriodInterval::Zero.as_i32(), 0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "i32", into = "i32")]
+pub enum ForecastPeriodInterval {
+    /// Interval of 0 (forecast endpoints only)
+    Zero = 0,
+    /// 1 minute
+    OneMinute = 1,
+    /// 1 hour (60 minutes)
+    OneHour = 60,
+    /// 1 day (1440 minutes)
+    OneDay = 1440,
+}
+
+impl ForecastPeriodInterval {
+    pub fn as_i32(&self) -> i32 {
+        *self as i32
+    }
+}
+
+impl TryFrom<i32> for ForecastPeriodInterval {
+    type Error = String;
+
+    fn try_from(minutes: i32) -> Result<Self, Self::Error> {
+        match minutes {
+            0 => Ok(ForecastPeriodInterval::Zero),
+            minutes => PeriodInterval::try_from(minutes).map(Into::into),
+        }
+    }
+}
+
+impl From<ForecastPeriodInterval> for i32 {
+    fn from(interval: ForecastPeriodInterval) -> Self {
+        interval.as_i32()
+    }
+}
+
+impl From<PeriodInterval> for ForecastPeriodInterval {
+    fn from(interval: PeriodInterval) -> Self {
+        match interval {
+            PeriodInterval::OneMinute => ForecastPeriodInterval::OneMinute,
+            PeriodInterval::OneHour => ForecastPeriodInterval::OneHour,
+            PeriodInterval::OneDay => ForecastPeriodInterval::OneDay,
+        }
+    }
+}
+
 /// Query parameters for get_events endpoint
-#[derive(Debug, Clone, Default)]
+///
+/// Like the other params structs, (de)serializes with the API's camelCase
+/// names and missing fields left at their defaults, e.g. to save filter
+/// presets.
+///
+/// ```
+/// use dflow_api_client::prediction::{GetEventsParams, MarketStatus, SortField};
+///
+/// let preset: GetEventsParams =
+///     serde_json::from_str(r#"{"status":"active","sort":"volume24h"}"#).unwrap();
+/// assert_eq!(preset.status, Some(MarketStatus::Active));
+/// assert!(matches!(preset.sort, Some(SortField::Volume24h)));
+///
+/// let json = serde_json::to_string(&preset).unwrap();
+/// let reloaded: GetEventsParams = serde_json::from_str(&json).unwrap();
+/// assert_eq!(reloaded.status, preset.status);
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct GetEventsParams {
     /// Maximum number of events to return
     pub limit: Option<i32>,
@@ -314,7 +981,8 @@ pub struct GetEventsParams {
     pub with_nested_markets: Option<bool>,
     /// Pagination cursor (number of events to skip)
     pub cursor: Option<i32>,
-    /// Filter by series tickers (comma-separated, max 25)
+    /// Filter by series tickers (comma-separated, max 25; see
+    /// `get_events_by_series`)
     pub series_tickers: Option<String>,
     /// Filter events that are initialized
     pub is_initialized: Option<bool>,
@@ -324,8 +992,95 @@ pub struct GetEventsParams {
     pub sort: Option<SortField>,
 }
 
-/// Query parameters for get_markets endpoint
+impl GetEventsParams {
+    /// Start building event query parameters.
+    ///
+    /// ```
+    /// use dflow_api_client::prediction::{GetEventsParams, MarketStatus};
+    ///
+    /// let params = GetEventsParams::builder()
+    ///     .status(MarketStatus::Active)
+    ///     .limit(10)
+    ///     .with_nested_markets(true)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(params.limit, Some(10));
+    ///
+    /// assert!(GetEventsParams::builder().limit(0).build().is_err());
+    /// ```
+    pub fn builder() -> GetEventsParamsBuilder {
+        GetEventsParamsBuilder::default()
+    }
+
+    /// Check the parameters before sending them.
+    ///
+    /// # Returns
+    ///
+    /// `InvalidParameter` if `limit` isn't positive or `cursor` is negative.
+    pub fn validate(&self) -> ApiResult<()> {
+        validate_paging(self.limit, self.cursor)
+    }
+}
+
+/// Builder for [`GetEventsParams`].
 #[derive(Debug, Clone, Default)]
+pub struct GetEventsParamsBuilder {
+    params: GetEventsParams,
+}
+
+impl GetEventsParamsBuilder {
+    /// Set the maximum number of events to return.
+    pub fn limit(mut self, limit: i32) -> Self {
+        self.params.limit = Some(limit);
+        self
+    }
+
+    /// Include nested markets in the response.
+    pub fn with_nested_markets(mut self, with_nested_markets: bool) -> Self {
+        self.params.with_nested_markets = Some(with_nested_markets);
+        self
+    }
+
+    /// Set the pagination cursor (number of events to skip).
+    pub fn cursor(mut self, cursor: i32) -> Self {
+        self.params.cursor = Some(cursor);
+        self
+    }
+
+    /// Filter by series tickers (comma-separated, max 25).
+    pub fn series_tickers(mut self, series_tickers: impl Into<String>) -> Self {
+        self.params.series_tickers = Some(series_tickers.into());
+        self
+    }
+
+    /// Filter events that are initialized.
+    pub fn is_initialized(mut self, is_initialized: bool) -> Self {
+        self.params.is_initialized = Some(is_initialized);
+        self
+    }
+
+    /// Filter by market status.
+    pub fn status(mut self, status: MarketStatus) -> Self {
+        self.params.status = Some(status);
+        self
+    }
+
+    /// Set the sort field.
+    pub fn sort(mut self, sort: SortField) -> Self {
+        self.params.sort = Some(sort);
+        self
+    }
+
+    /// Build the parameters, see [`GetEventsParams::validate`].
+    pub fn build(self) -> ApiResult<GetEventsParams> {
+        self.params.validate()?;
+        Ok(self.params)
+    }
+}
+
+/// Query parameters for get_markets endpoint
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct GetMarketsParams {
     /// Maximum number of markets to return
     pub limit: Option<i32>,
@@ -339,8 +1094,105 @@ pub struct GetMarketsParams {
     pub sort: Option<SortField>,
 }
 
-/// Query parameters for get_outcome_mints endpoint
+impl GetMarketsParams {
+    /// Start building market query parameters.
+    ///
+    /// ```
+    /// use dflow_api_client::prediction::{
+    ///     GetMarketsParams, MarketStatus, SortField,
+    /// };
+    ///
+    /// let params = GetMarketsParams::builder()
+    ///     .status(MarketStatus::Active)
+    ///     .sort(SortField::Volume)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(params.sort.is_some());
+    ///
+    /// assert!(GetMarketsParams::builder().cursor(-1).build().is_err());
+    /// ```
+    pub fn builder() -> GetMarketsParamsBuilder {
+        GetMarketsParamsBuilder::default()
+    }
+
+    /// Check the parameters before sending them.
+    ///
+    /// # Returns
+    ///
+    /// `InvalidParameter` if `limit` isn't positive or `cursor` is negative.
+    pub fn validate(&self) -> ApiResult<()> {
+        validate_paging(self.limit, self.cursor)
+    }
+}
+
+/// Builder for [`GetMarketsParams`].
 #[derive(Debug, Clone, Default)]
+pub struct GetMarketsParamsBuilder {
+    params: GetMarketsParams,
+}
+
+impl GetMarketsParamsBuilder {
+    /// Set the maximum number of markets to return.
+    pub fn limit(mut self, limit: i32) -> Self {
+        self.params.limit = Some(limit);
+        self
+    }
+
+    /// Set the pagination cursor (number of markets to skip).
+    pub fn cursor(mut self, cursor: i32) -> Self {
+        self.params.cursor = Some(cursor);
+        self
+    }
+
+    /// Filter markets that are initialized.
+    pub fn is_initialized(mut self, is_initialized: bool) -> Self {
+        self.params.is_initialized = Some(is_initialized);
+        self
+    }
+
+    /// Filter by market status.
+    pub fn status(mut self, status: MarketStatus) -> Self {
+        self.params.status = Some(status);
+        self
+    }
+
+    /// Set the sort field.
+    pub fn sort(mut self, sort: SortField) -> Self {
+        self.params.sort = Some(sort);
+        self
+    }
+
+    /// Build the parameters, see [`GetMarketsParams::validate`].
+    pub fn build(self) -> ApiResult<GetMarketsParams> {
+        self.params.validate()?;
+        Ok(self.params)
+    }
+}
+
+/// Check the paging parameters shared by the list endpoints.
+fn validate_paging(limit: Option<i32>, cursor: Option<i32>) -> ApiResult<()> {
+    if let Some(limit) = limit
+        && limit < 1
+    {
+        return Err(DflowApiError::InvalidParameter(format!(
+            "limit must be positive, got {}",
+            limit
+        )));
+    }
+    if let Some(cursor) = cursor
+        && cursor < 0
+    {
+        return Err(DflowApiError::InvalidParameter(format!(
+            "cursor must not be negative, got {}",
+            cursor
+        )));
+    }
+    Ok(())
+}
+
+/// Query parameters for get_outcome_mints endpoint
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct GetOutcomeMintsParams {
     /// Minimum close timestamp (Unix timestamp in seconds).
     /// Only markets with close_time >= min_close_ts will be included.
@@ -348,18 +1200,20 @@ pub struct GetOutcomeMintsParams {
 }
 
 /// Query parameters for candlestick endpoints
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct GetCandlesticksParams {
     /// Start timestamp (Unix timestamp in seconds)
     pub start_ts: Option<i64>,
     /// End timestamp (Unix timestamp in seconds)
     pub end_ts: Option<i64>,
-    /// Time period length of each candlestick in minutes (1, 60, or 1440)
-    pub period_interval: Option<i32>,
+    /// Time period length of each candlestick
+    pub period_interval: Option<PeriodInterval>,
 }
 
 /// Query parameters for forecast percentile history endpoint
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct GetForecastPercentileHistoryParams {
     /// Comma-separated list of percentile values (0-10000, max 10 values)
     pub percentiles: Option<String>,
@@ -367,8 +1221,8 @@ pub struct GetForecastPercentileHistoryParams {
     pub start_ts: Option<i64>,
     /// End timestamp (Unix timestamp in seconds)
     pub end_ts: Option<i64>,
-    /// Period interval in minutes (0, 1, 60, or 1440)
-    pub period_interval: Option<i32>,
+    /// Period interval
+    pub period_interval: Option<ForecastPeriodInterval>,
 }
 
 // =============================================================================
@@ -386,7 +1240,7 @@ pub struct OrderLevel {
 }
 
 /// Orderbook data for a market
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Orderbook {
     /// Market ticker
@@ -405,11 +1259,462 @@ pub struct Orderbook {
     pub no_asks: Vec<OrderLevel>,
 }
 
+impl Orderbook {
+    /// Bid levels of an outcome.
+    fn bids(&self, side: Side) -> &[OrderLevel] {
+        match side {
+            Side::Yes => &self.yes_bids,
+            Side::No => &self.no_bids,
+        }
+    }
+
+    /// Ask levels of an outcome.
+    fn asks(&self, side: Side) -> &[OrderLevel] {
+        match side {
+            Side::Yes => &self.yes_asks,
+            Side::No => &self.no_asks,
+        }
+    }
+
+    /// Quantity that can be bought without paying more than `max_price`.
+    ///
+    /// Sums the asks of the outcome priced at or below `max_price`.
+    ///
+    /// # Arguments
+    ///
+    /// * `side` - Outcome to buy
+    /// * `max_price` - Highest acceptable price
+    pub fn size_available_up_to(&self, side: Side, max_price: f64) -> i64 {
+        self.asks(side)
+            .iter()
+            .filter(|level| level.price <= max_price)
+            .map(|level| level.quantity)
+            .sum()
+    }
+
+    /// Quantity that can be sold without receiving less than `min_price`.
+    ///
+    /// Sums the bids of the outcome priced at or above `min_price`.
+    ///
+    /// # Arguments
+    ///
+    /// * `side` - Outcome to sell
+    /// * `min_price` - Lowest acceptable price
+    pub fn size_available_down_to(&self, side: Side, min_price: f64) -> i64 {
+        self.bids(side)
+            .iter()
+            .filter(|level| level.price >= min_price)
+            .map(|level| level.quantity)
+            .sum()
+    }
+
+    /// Volume-weighted average price of buying `quantity` of an outcome.
+    ///
+    /// Walks the asks from the lowest price up, taking part of the last
+    /// level if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `side` - Outcome to buy
+    /// * `quantity` - Number of contracts to buy
+    ///
+    /// # Returns
+    ///
+    /// The average fill price, or `None` if `quantity` isn't positive or the
+    /// asks don't hold enough depth.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dflow_api_client::prediction::{OrderLevel, Orderbook, Side};
+    ///
+    /// let book = Orderbook {
+    ///     yes_asks: vec![
+    ///         OrderLevel { price: 0.60, quantity: 50 },
+    ///         OrderLevel { price: 0.50, quantity: 100 },
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(book.size_available_up_to(Side::Yes, 0.55), 100);
+    /// assert_eq!(book.size_available_up_to(Side::Yes, 0.60), 150);
+    ///
+    /// // 100 at 0.50 and 20 of the 50 at 0.60
+    /// let price = book.average_fill_price(Side::Yes, 120).unwrap();
+    /// assert!((price - 0.51666).abs() < 1e-4);
+    /// assert_eq!(book.average_fill_price(Side::Yes, 151), None);
+    /// assert_eq!(book.average_fill_price(Side::No, 1), None);
+    /// ```
+    pub fn average_fill_price(&self, side: Side, quantity: i64) -> Option<f64> {
+        if quantity <= 0 {
+            return None;
+        }
+
+        let mut asks: Vec<&OrderLevel> = self.asks(side).iter().collect();
+        asks.sort_by(|a, b| a.price.total_cmp(&b.price));
+
+        let mut remaining = quantity;
+        let mut cost = 0.0;
+        for level in asks {
+            let filled = remaining.min(level.quantity.max(0));
+            cost += filled as f64 * level.price;
+            remaining -= filled;
+            if remaining == 0 {
+                return Some(cost / quantity as f64);
+            }
+        }
+
+        None
+    }
+
+    /// Highest bid of an outcome, or `None` if there are no bids.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dflow_api_client::prediction::{OrderLevel, Orderbook, Side};
+    ///
+    /// let book = Orderbook {
+    ///     yes_bids: vec![
+    ///         OrderLevel { price: 0.40, quantity: 10 },
+    ///         OrderLevel { price: 0.45, quantity: 5 },
+    ///     ],
+    ///     yes_asks: vec![
+    ///         OrderLevel { price: 0.55, quantity: 5 },
+    ///         OrderLevel { price: 0.50, quantity: 10 },
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(book.best_yes_bid(), Some(0.45));
+    /// assert_eq!(book.best_yes_ask(), Some(0.50));
+    /// assert!((book.yes_mid().unwrap() - 0.475).abs() < 1e-9);
+    /// assert!((book.yes_spread().unwrap() - 0.05).abs() < 1e-9);
+    ///
+    /// assert_eq!(book.best_no_bid(), None);
+    /// assert_eq!(book.no_mid(), None);
+    /// assert_eq!(book.spread(Side::No), None);
+    /// ```
+    pub fn best_bid(&self, side: Side) -> Option<f64> {
+        self.bids(side)
+            .iter()
+            .map(|level| level.price)
+            .max_by(f64::total_cmp)
+    }
+
+    /// Lowest ask of an outcome, or `None` if there are no asks.
+    pub fn best_ask(&self, side: Side) -> Option<f64> {
+        self.asks(side)
+            .iter()
+            .map(|level| level.price)
+            .min_by(f64::total_cmp)
+    }
+
+    /// Midpoint between the best bid and ask of an outcome.
+    ///
+    /// Returns `None` if either side of the book is empty.
+    pub fn mid(&self, side: Side) -> Option<f64> {
+        Some((self.best_bid(side)? + self.best_ask(side)?) / 2.0)
+    }
+
+    /// Best ask minus best bid of an outcome.
+    ///
+    /// Returns `None` if either side of the book is empty.
+    pub fn spread(&self, side: Side) -> Option<f64> {
+        Some(self.best_ask(side)? - self.best_bid(side)?)
+    }
+
+    /// Highest YES bid.
+    pub fn best_yes_bid(&self) -> Option<f64> {
+        self.best_bid(Side::Yes)
+    }
+
+    /// Lowest YES ask.
+    pub fn best_yes_ask(&self) -> Option<f64> {
+        self.best_ask(Side::Yes)
+    }
+
+    /// YES midpoint.
+    pub fn yes_mid(&self) -> Option<f64> {
+        self.mid(Side::Yes)
+    }
+
+    /// YES spread.
+    pub fn yes_spread(&self) -> Option<f64> {
+        self.spread(Side::Yes)
+    }
+
+    /// Highest NO bid.
+    pub fn best_no_bid(&self) -> Option<f64> {
+        self.best_bid(Side::No)
+    }
+
+    /// Lowest NO ask.
+    pub fn best_no_ask(&self) -> Option<f64> {
+        self.best_ask(Side::No)
+    }
+
+    /// NO midpoint.
+    pub fn no_mid(&self) -> Option<f64> {
+        self.mid(Side::No)
+    }
+
+    /// NO spread.
+    pub fn no_spread(&self) -> Option<f64> {
+        self.spread(Side::No)
+    }
+
+    /// Bid levels of an outcome, best (highest price) first.
+    pub fn sorted_bids(&self, side: Side) -> Vec<OrderLevel> {
+        let mut bids = self.bids(side).to_vec();
+        bids.sort_by(|a, b| b.price.total_cmp(&a.price));
+        bids
+    }
+
+    /// Ask levels of an outcome, best (lowest price) first.
+    pub fn sorted_asks(&self, side: Side) -> Vec<OrderLevel> {
+        let mut asks = self.asks(side).to_vec();
+        asks.sort_by(|a, b| a.price.total_cmp(&b.price));
+        asks
+    }
+
+    /// YES bids, highest price first.
+    pub fn sorted_yes_bids(&self) -> Vec<OrderLevel> {
+        self.sorted_bids(Side::Yes)
+    }
+
+    /// YES asks, lowest price first.
+    pub fn sorted_yes_asks(&self) -> Vec<OrderLevel> {
+        self.sorted_asks(Side::Yes)
+    }
+
+    /// NO bids, highest price first.
+    pub fn sorted_no_bids(&self) -> Vec<OrderLevel> {
+        self.sorted_bids(Side::No)
+    }
+
+    /// NO asks, lowest price first.
+    pub fn sorted_no_asks(&self) -> Vec<OrderLevel> {
+        self.sorted_asks(Side::No)
+    }
+
+    /// Depth of an outcome with running totals, e.g. for a depth chart.
+    ///
+    /// Both sides start at the best price and move away from the spread.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dflow_api_client::prediction::{OrderLevel, Orderbook, Side};
+    ///
+    /// let book = Orderbook {
+    ///     yes_bids: vec![
+    ///         OrderLevel { price: 0.40, quantity: 10 },
+    ///         OrderLevel { price: 0.45, quantity: 5 },
+    ///     ],
+    ///     yes_asks: vec![
+    ///         OrderLevel { price: 0.55, quantity: 5 },
+    ///         OrderLevel { price: 0.50, quantity: 10 },
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let prices: Vec<f64> =
+    ///     book.sorted_yes_bids().iter().map(|level| level.price).collect();
+    /// assert_eq!(prices, [0.45, 0.40]);
+    ///
+    /// let depth = book.cumulative_depth(Side::Yes);
+    /// let bids: Vec<(f64, i64)> = depth
+    ///     .bids
+    ///     .iter()
+    ///     .map(|level| (level.price, level.total_quantity))
+    ///     .collect();
+    /// let asks: Vec<(f64, i64)> = depth
+    ///     .asks
+    ///     .iter()
+    ///     .map(|level| (level.price, level.total_quantity))
+    ///     .collect();
+    /// assert_eq!(bids, [(0.45, 5), (0.40, 15)]);
+    /// assert_eq!(asks, [(0.50, 10), (0.55, 15)]);
+    /// ```
+    pub fn cumulative_depth(&self, side: Side) -> CumulativeDepth {
+        CumulativeDepth {
+            bids: running_totals(self.sorted_bids(side)),
+            asks: running_totals(self.sorted_asks(side)),
+        }
+    }
+}
+
+/// Pair sorted levels with the total quantity up to and including each.
+fn running_totals(levels: Vec<OrderLevel>) -> Vec<DepthLevel> {
+    let mut total_quantity = 0;
+    levels
+        .into_iter()
+        .map(|level| {
+            total_quantity += level.quantity;
+            DepthLevel {
+                price: level.price,
+                quantity: level.quantity,
+                total_quantity,
+            }
+        })
+        .collect()
+}
+
+/// Orderbook level with the quantity available up to its price
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DepthLevel {
+    /// Price at this level
+    pub price: f64,
+    /// Quantity at this level
+    pub quantity: i64,
+    /// Quantity at this level and all better ones
+    pub total_quantity: i64,
+}
+
+/// Cumulative depth of one outcome, see [`Orderbook::cumulative_depth`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CumulativeDepth {
+    /// Bids, highest price first
+    pub bids: Vec<DepthLevel>,
+    /// Asks, lowest price first
+    pub asks: Vec<DepthLevel>,
+}
+
+/// Orderbook depth as `(price, quantity)` levels.
+///
+/// A common form for the REST [`Orderbook`] (`Vec<OrderLevel>`) and the
+/// WebSocket `OrderbookUpdate` (price-string maps), built with `From`. Every
+/// side is sorted best price first: bids descending, asks ascending.
+///
+/// WebSocket updates currently only carry bids, so a `DepthBook` built from
+/// one has empty ask sides.
+///
+/// # Example
+///
+/// ```
+/// use dflow_api_client::prediction::{DepthBook, OrderLevel, Orderbook};
+///
+/// let book = Orderbook {
+///     ticker: "M".to_string(),
+///     yes_bids: vec![
+///         OrderLevel { price: 0.40, quantity: 10 },
+///         OrderLevel { price: 0.45, quantity: 5 },
+///     ],
+///     ..Default::default()
+/// };
+///
+/// let depth = DepthBook::from(&book);
+/// assert_eq!(depth.ticker, "M");
+/// assert_eq!(depth.yes_bids, [(0.45, 5), (0.40, 10)]);
+/// assert!(depth.yes_asks.is_empty());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DepthBook {
+    /// Market ticker
+    pub ticker: String,
+    /// YES bids, highest price first
+    pub yes_bids: Vec<(f64, i64)>,
+    /// YES asks, lowest price first
+    pub yes_asks: Vec<(f64, i64)>,
+    /// NO bids, highest price first
+    pub no_bids: Vec<(f64, i64)>,
+    /// NO asks, lowest price first
+    pub no_asks: Vec<(f64, i64)>,
+}
+
+impl DepthBook {
+    /// Sort every side best price first.
+    pub fn sorted(mut self) -> Self {
+        for bids in [&mut self.yes_bids, &mut self.no_bids] {
+            bids.sort_by(|a, b| b.0.total_cmp(&a.0));
+        }
+        for asks in [&mut self.yes_asks, &mut self.no_asks] {
+            asks.sort_by(|a, b| a.0.total_cmp(&b.0));
+        }
+        self
+    }
+}
+
+impl From<&Orderbook> for DepthBook {
+    fn from(book: &Orderbook) -> Self {
+        let levels = |levels: &[OrderLevel]| {
+            levels
+                .iter()
+                .map(|level| (level.price, level.quantity))
+                .collect()
+        };
+        DepthBook {
+            ticker: book.ticker.clone(),
+            yes_bids: levels(&book.yes_bids),
+            yes_asks: levels(&book.yes_asks),
+            no_bids: levels(&book.no_bids),
+            no_asks: levels(&book.no_asks),
+        }
+        .sorted()
+    }
+}
+
+impl From<Orderbook> for DepthBook {
+    fn from(book: Orderbook) -> Self {
+        DepthBook::from(&book)
+    }
+}
+
+/// Outcome of a binary market, also the taker side of a trade
+///
+/// Serializes to and from the lowercase API strings. Unlike [`MarketType`],
+/// there is no fallback variant: a binary market only has the two outcomes,
+/// so any other value fails to parse, and so does the `Trade` or
+/// `TradeUpdate` carrying it.
+///
+/// # Example
+///
+/// ```
+/// use dflow_api_client::prediction::Side;
+///
+/// let side: Side = serde_json::from_str(r#""no""#).unwrap();
+/// assert_eq!(side, Side::No);
+/// assert_eq!(serde_json::to_string(&Side::Yes).unwrap(), r#""yes""#);
+/// assert!(serde_json::from_str::<Side>(r#""maybe""#).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Side {
+    Yes,
+    No,
+}
+
+impl Side {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Side::Yes => "yes",
+            Side::No => "no",
+        }
+    }
+}
+
 // =============================================================================
 // Trade Types
 // =============================================================================
 
 /// A single trade record
+///
+/// # Example
+///
+/// ```
+/// use dflow_api_client::prediction::{Side, Trade};
+///
+/// let json = serde_json::json!({
+///     "tradeId": "1", "ticker": "T", "count": 10, "price": 42,
+///     "yesPrice": 42, "noPrice": 58, "yesPriceDollars": "0.4200",
+///     "noPriceDollars": "0.5800", "takerSide": "no", "createdTime": 1000,
+/// });
+/// let trade: Trade = serde_json::from_value(json.clone()).unwrap();
+///
+/// assert_eq!(trade.taker_side, Side::No);
+/// assert_eq!(serde_json::to_value(&trade).unwrap(), json);
+/// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Trade {
@@ -429,12 +1734,51 @@ pub struct Trade {
     pub yes_price_dollars: String,
     /// No price in dollars
     pub no_price_dollars: String,
-    /// Taker side ("yes" or "no")
-    pub taker_side: String,
+    /// Taker side
+    pub taker_side: Side,
     /// Trade creation time (Unix timestamp in milliseconds)
     pub created_time: i64,
 }
 
+impl Trade {
+    /// YES price in dollars as a number, or `None` if malformed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dflow_api_client::prediction::Trade;
+    ///
+    /// let trade: Trade = serde_json::from_value(serde_json::json!({
+    ///     "tradeId": "1", "ticker": "T", "count": 10, "price": 42,
+    ///     "yesPrice": 42, "noPrice": 58, "yesPriceDollars": "0.4200",
+    ///     "noPriceDollars": "", "takerSide": "yes", "createdTime": 1000,
+    /// }))
+    /// .unwrap();
+    ///
+    /// assert_eq!(trade.yes_price_dollars_f64(), Some(0.42));
+    /// assert_eq!(trade.no_price_dollars_f64(), None);
+    /// assert!(trade.try_no_price_dollars().is_err());
+    /// ```
+    pub fn yes_price_dollars_f64(&self) -> Option<f64> {
+        self.try_yes_price_dollars().ok()
+    }
+
+    /// YES price in dollars as a number, failing if malformed.
+    pub fn try_yes_price_dollars(&self) -> ApiResult<f64> {
+        parse_price("yes_price_dollars", &self.yes_price_dollars)
+    }
+
+    /// NO price in dollars as a number, or `None` if malformed.
+    pub fn no_price_dollars_f64(&self) -> Option<f64> {
+        self.try_no_price_dollars().ok()
+    }
+
+    /// NO price in dollars as a number, failing if malformed.
+    pub fn try_no_price_dollars(&self) -> ApiResult<f64> {
+        parse_price("no_price_dollars", &self.no_price_dollars)
+    }
+}
+
 /// Response for get_trades endpoint
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -446,8 +1790,21 @@ pub struct TradesResponse {
     pub cursor: Option<String>,
 }
 
+impl Paginated for TradesResponse {
+    type Item = Trade;
+
+    fn next_cursor(&self) -> Option<Cursor> {
+        self.cursor.clone().map(Cursor::Token)
+    }
+
+    fn items(self) -> Vec<Trade> {
+        self.trades
+    }
+}
+
 /// Query parameters for get_trades endpoint
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct GetTradesParams {
     /// Maximum number of trades to return (1-1000, default 100)
     pub limit: Option<i32>,
@@ -461,6 +1818,27 @@ pub struct GetTradesParams {
     pub max_ts: Option<i64>,
 }
 
+impl GetTradesParams {
+    /// Check the parameters before sending them.
+    ///
+    /// # Returns
+    ///
+    /// `InvalidParameter` if `limit` is outside 1 to
+    /// [`MAX_TRADES_LIMIT`](super::MAX_TRADES_LIMIT).
+    pub fn validate(&self) -> ApiResult<()> {
+        if let Some(limit) = self.limit
+            && !(1..=super::MAX_TRADES_LIMIT).contains(&limit)
+        {
+            return Err(DflowApiError::InvalidParameter(format!(
+                "limit must be between 1 and {}, got {}",
+                super::MAX_TRADES_LIMIT,
+                limit
+            )));
+        }
+        Ok(())
+    }
+}
+
 // =============================================================================
 // Series Types
 // =============================================================================
@@ -512,8 +1890,46 @@ pub struct SeriesResponse {
     pub series: Vec<Series>,
 }
 
+impl SeriesResponse {
+    /// Group the series by category, keeping their order within each.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dflow_api_client::prediction::SeriesResponse;
+    ///
+    /// let response: SeriesResponse = serde_json::from_value(serde_json::json!({
+    ///     "series": [
+    ///         { "ticker": "FED", "title": "Fed", "category": "Economics" },
+    ///         { "ticker": "PRES", "title": "President", "category": "Politics" },
+    ///         { "ticker": "CPI", "title": "CPI", "category": "Economics" },
+    ///     ],
+    /// }))
+    /// .unwrap();
+    ///
+    /// let grouped = response.group_by_category();
+    /// let economics: Vec<&str> = grouped["Economics"]
+    ///     .iter()
+    ///     .map(|series| series.ticker.as_str())
+    ///     .collect();
+    /// assert_eq!(economics, ["FED", "CPI"]);
+    /// assert_eq!(grouped["Politics"].len(), 1);
+    /// ```
+    pub fn group_by_category(self) -> HashMap<String, Vec<Series>> {
+        let mut grouped: HashMap<String, Vec<Series>> = HashMap::new();
+        for series in self.series {
+            grouped
+                .entry(series.category.clone())
+                .or_default()
+                .push(series);
+        }
+        grouped
+    }
+}
+
 /// Query parameters for get_series endpoint
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct GetSeriesParams {
     /// Filter series by category (e.g., Politics, Economics, Entertainment)
     pub category: Option<String>,
@@ -534,7 +1950,32 @@ pub struct GetSeriesParams {
 #[serde(rename_all = "camelCase")]
 pub struct TagsByCategoriesResponse {
     /// Map of category to list of tags
-    pub tags_by_categories: std::collections::HashMap<String, Vec<String>>,
+    pub tags_by_categories: HashMap<String, Vec<String>>,
+}
+
+impl TagsByCategoriesResponse {
+    /// Tags of a single category (empty if the category is absent).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dflow_api_client::prediction::TagsByCategoriesResponse;
+    ///
+    /// let response: TagsByCategoriesResponse = serde_json::from_str(
+    ///     r#"{"tagsByCategories": {"Sports": ["NBA", "NFL"], "Crypto": []}}"#,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(response.tags_for("Sports"), ["NBA", "NFL"]);
+    /// assert!(response.tags_for("Crypto").is_empty());
+    /// assert!(response.tags_for("Politics").is_empty());
+    /// ```
+    pub fn tags_for(&self, category: &str) -> &[String] {
+        self.tags_by_categories
+            .get(category)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
 }
 
 // =============================================================================
@@ -556,7 +1997,7 @@ pub struct FiltersBySportsResponse {
 // =============================================================================
 
 /// Sort order for search results
-#[derive(Debug, Clone, Copy, Serialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum SortOrder {
     Asc,
@@ -583,8 +2024,21 @@ pub struct SearchResponse {
     pub cursor: Option<i32>,
 }
 
+impl Paginated for SearchResponse {
+    type Item = Event;
+
+    fn next_cursor(&self) -> Option<Cursor> {
+        self.cursor.map(Cursor::Offset)
+    }
+
+    fn items(self) -> Vec<Event> {
+        self.events
+    }
+}
+
 /// Query parameters for search endpoint
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct SearchParams {
     /// The query string to search for (required)
     pub q: String,
@@ -602,6 +2056,97 @@ pub struct SearchParams {
     pub with_market_accounts: Option<bool>,
 }
 
+impl SearchParams {
+    /// Start building search parameters for the query `q`.
+    ///
+    /// ```
+    /// use dflow_api_client::prediction::{SearchParams, SortField, SortOrder};
+    ///
+    /// let params = SearchParams::builder("election")
+    ///     .sort(SortField::Volume)
+    ///     .order(SortOrder::Desc)
+    ///     .limit(20)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(params.q, "election");
+    ///
+    /// assert!(SearchParams::builder("  ").build().is_err());
+    /// ```
+    pub fn builder(q: impl Into<String>) -> SearchParamsBuilder {
+        SearchParamsBuilder {
+            params: SearchParams {
+                q: q.into(),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Check the parameters before sending them.
+    ///
+    /// # Returns
+    ///
+    /// `InvalidParameter` if `q` is blank, `limit` isn't positive or
+    /// `cursor` is negative.
+    pub fn validate(&self) -> ApiResult<()> {
+        if self.q.trim().is_empty() {
+            return Err(DflowApiError::InvalidParameter(
+                "search query `q` must not be empty".to_string(),
+            ));
+        }
+        validate_paging(self.limit, self.cursor)
+    }
+}
+
+/// Builder for [`SearchParams`].
+#[derive(Debug, Clone)]
+pub struct SearchParamsBuilder {
+    params: SearchParams,
+}
+
+impl SearchParamsBuilder {
+    /// Set the field to sort by.
+    pub fn sort(mut self, sort: SortField) -> Self {
+        self.params.sort = Some(sort);
+        self
+    }
+
+    /// Set the sort order.
+    pub fn order(mut self, order: SortOrder) -> Self {
+        self.params.order = Some(order);
+        self
+    }
+
+    /// Set the maximum number of results to return.
+    pub fn limit(mut self, limit: i32) -> Self {
+        self.params.limit = Some(limit);
+        self
+    }
+
+    /// Set the pagination cursor.
+    pub fn cursor(mut self, cursor: i32) -> Self {
+        self.params.cursor = Some(cursor);
+        self
+    }
+
+    /// Include nested markets in the response.
+    pub fn with_nested_markets(mut self, with_nested_markets: bool) -> Self {
+        self.params.with_nested_markets = Some(with_nested_markets);
+        self
+    }
+
+    /// Include market account information.
+    pub fn with_market_accounts(mut self, with_market_accounts: bool) -> Self {
+        self.params.with_market_accounts = Some(with_market_accounts);
+        self
+    }
+
+    /// Build the parameters, see [`SearchParams::validate`].
+    pub fn build(self) -> ApiResult<SearchParams> {
+        self.params.validate()?;
+        Ok(self.params)
+    }
+}
+
 // =============================================================================
 // Live Data Types
 // =============================================================================
@@ -615,3 +2160,348 @@ pub struct LiveDataResponse {
     #[serde(flatten)]
     pub data: serde_json::Value,
 }
+
+impl LiveDataResponse {
+    /// Whether the response contains no live data.
+    pub fn is_empty(&self) -> bool {
+        match &self.data {
+            serde_json::Value::Null => true,
+            serde_json::Value::Object(map) => map.is_empty(),
+            serde_json::Value::Array(items) => items.is_empty(),
+            _ => false,
+        }
+    }
+
+    /// Merge the live data of another response into this one.
+    ///
+    /// Used to combine the responses of chunked requests. Arrays under the
+    /// same key are concatenated and objects are merged key by key, applying
+    /// the same rules recursively. For any other overlapping value, `other`
+    /// wins.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dflow_api_client::prediction::LiveDataResponse;
+    /// use serde_json::json;
+    ///
+    /// let mut live: LiveDataResponse = serde_json::from_value(json!({
+    ///     "liveDatas": [{ "milestoneId": "a" }],
+    ///     "meta": { "source": "kalshi", "updated": 1 },
+    /// }))
+    /// .unwrap();
+    /// let other: LiveDataResponse = serde_json::from_value(json!({
+    ///     "liveDatas": [{ "milestoneId": "b" }],
+    ///     "meta": { "updated": 2 },
+    /// }))
+    /// .unwrap();
+    ///
+    /// live.merge(other);
+    /// assert_eq!(
+    ///     live.data,
+    ///     json!({
+    ///         "liveDatas": [{ "milestoneId": "a" }, { "milestoneId": "b" }],
+    ///         "meta": { "source": "kalshi", "updated": 2 },
+    ///     })
+    /// );
+    /// ```
+    pub fn merge(&mut self, other: LiveDataResponse) {
+        merge_json(&mut self.data, other.data);
+    }
+
+    /// Parse the live data of every milestone in the response.
+    ///
+    /// Reads the `liveDatas` list (or a single `liveData` object), accepting
+    /// snake_case keys as relayed from Kalshi. Entries that don't have the
+    /// milestone shape are kept as [`LiveData::Other`]; a response without
+    /// either key is returned whole as a single `Other`. Milestone details
+    /// stay raw JSON, see [`MilestoneLiveData`].
+    ///
+    /// # Returns
+    ///
+    /// The parsed entries, or `InvalidData` if `liveDatas` isn't a list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dflow_api_client::prediction::{LiveData, LiveDataResponse};
+    /// use serde_json::json;
+    ///
+    /// let live: LiveDataResponse = serde_json::from_value(json!({
+    ///     "live_datas": [
+    ///         {
+    ///             "milestone_id": "m-1",
+    ///             "type": "football_game",
+    ///             "details": { "home_points": 21, "away_points": 14 },
+    ///         },
+    ///         "unexpected",
+    ///     ],
+    /// }))
+    /// .unwrap();
+    ///
+    /// let parsed = live.parsed().unwrap();
+    /// let LiveData::Milestone(game) = &parsed[0] else {
+    ///     panic!("expected a milestone");
+    /// };
+    /// assert_eq!(game.milestone_id, "m-1");
+    /// assert_eq!(game.kind, "football_game");
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// struct Score {
+    ///     home_points: u32,
+    ///     away_points: u32,
+    /// }
+    /// let score: Score = game.details_as().unwrap();
+    /// assert_eq!((score.home_points, score.away_points), (21, 14));
+    ///
+    /// assert_eq!(parsed[1], LiveData::Other(json!("unexpected")));
+    /// ```
+    pub fn parsed(&self) -> ApiResult<Vec<LiveData>> {
+        let list = ["liveDatas", "live_datas"]
+            .iter()
+            .find_map(|key| self.data.get(key));
+        if let Some(list) = list {
+            let entries = list.as_array().ok_or_else(|| {
+                DflowApiError::InvalidData(format!(
+                    "live data list is not an array: {}",
+                    list
+                ))
+            })?;
+            return Ok(entries.iter().cloned().map(LiveData::from).collect());
+        }
+
+        let single = ["liveData", "live_data"]
+            .iter()
+            .find_map(|key| self.data.get(key));
+        let entry = single.unwrap_or(&self.data).clone();
+        Ok(vec![LiveData::from(entry)])
+    }
+}
+
+/// Live data of a single milestone.
+///
+/// Only the envelope is typed. The API doesn't document the shape of
+/// `details` for each milestone type (sports scores, economic indicators,
+/// ...), so this crate provides no per-type structs; deserialize the
+/// details into your own type with [`details_as`](Self::details_as).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MilestoneLiveData {
+    /// Milestone ID
+    #[serde(alias = "milestone_id")]
+    pub milestone_id: String,
+    /// Milestone type (e.g. a sports game or an economic indicator)
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Type-specific details
+    #[serde(default)]
+    pub details: serde_json::Value,
+}
+
+impl MilestoneLiveData {
+    /// Deserialize the details into a type matching the milestone type.
+    pub fn details_as<T: serde::de::DeserializeOwned>(&self) -> ApiResult<T> {
+        serde_json::from_value(self.details.clone()).map_err(|e| {
+            DflowApiError::InvalidData(format!(
+                "invalid {} details: {}",
+                self.kind, e
+            ))
+        })
+    }
+}
+
+/// A live data entry of a [`LiveDataResponse`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum LiveData {
+    /// Live data of a milestone
+    Milestone(MilestoneLiveData),
+    /// An entry of unknown shape, kept as raw JSON
+    Other(serde_json::Value),
+}
+
+impl From<serde_json::Value> for LiveData {
+    fn from(value: serde_json::Value) -> Self {
+        match MilestoneLiveData::deserialize(&value) {
+            Ok(milestone) => LiveData::Milestone(milestone),
+            Err(_) => LiveData::Other(value),
+        }
+    }
+}
+
+/// Merge `other` into `value`: arrays are concatenated, objects merged
+/// recursively, and anything else is replaced.
+fn merge_json(value: &mut serde_json::Value, other: serde_json::Value) {
+    use serde_json::Value;
+
+    match (value, other) {
+        (Value::Array(items), Value::Array(other)) => items.extend(other),
+        (Value::Object(map), Value::Object(other)) => {
+            for (key, other) in other {
+                match map.get_mut(&key) {
+                    Some(value) => merge_json(value, other),
+                    None => {
+                        map.insert(key, other);
+                    }
+                }
+            }
+        }
+        (value, other) => *value = other,
+    }
+}
+
+// =============================================================================
+// Portfolio Types
+// =============================================================================
+
+/// Current value of a single outcome token holding
+#[derive(Debug, Clone, PartialEq)]
+pub struct PositionValue {
+    /// Outcome mint address
+    pub mint: String,
+    /// Ticker of the market the mint belongs to
+    pub market_ticker: String,
+    /// Outcome of the mint
+    pub outcome: Side,
+    /// Quantity held, in contracts
+    pub quantity: i64,
+    /// Price per contract in dollars (`None` if the market has no bid)
+    pub price_dollars: Option<f64>,
+    /// Value of the position in dollars (0 if the price is unknown)
+    pub value_dollars: f64,
+}
+
+/// Current value of a set of outcome token holdings
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PortfolioValue {
+    /// Sum of all position values in dollars
+    pub total_dollars: f64,
+    /// Value of each position, in mint order
+    pub positions: Vec<PositionValue>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR_MS: i64 = 3_600_000;
+
+    fn market(extra: serde_json::Value) -> Market {
+        let mut json = serde_json::json!({
+            "ticker": "T", "title": "", "subtitle": "", "eventTicker": "E",
+            "marketType": "binary", "status": "active", "result": "",
+            "canCloseEarly": false, "openTime": 1000, "closeTime": 5000,
+            "expirationTime": 6000, "volume": 0, "openInterest": 0,
+            "rulesPrimary": "", "yesSubTitle": "", "noSubTitle": "",
+            "accounts": {},
+        });
+        json.as_object_mut()
+            .unwrap()
+            .extend(extra.as_object().unwrap().clone());
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn early_close_requires_can_close_early() {
+        let condition = "Resolves once the outcome is known";
+        let early = market(serde_json::json!({
+            "canCloseEarly": true, "earlyCloseCondition": condition,
+        }));
+        assert_eq!(early.early_close(), Some(condition));
+
+        let scheduled =
+            market(serde_json::json!({ "earlyCloseCondition": condition }));
+        assert_eq!(scheduled.early_close(), None);
+    }
+
+    #[test]
+    fn may_settle_before_close_or_after_open() {
+        // Scheduled to close at 5000
+        let scheduled = market(serde_json::json!({}));
+        assert!(scheduled.may_settle_before(5000));
+        assert!(!scheduled.may_settle_before(3000));
+
+        // Can close early, so it may settle any time after opening
+        let early = market(serde_json::json!({ "canCloseEarly": true }));
+        assert!(early.may_settle_before(3000));
+        assert!(!early.may_settle_before(500));
+    }
+
+    #[test]
+    fn zero_quotes_are_cleared_on_request() {
+        let quoted = market(serde_json::json!({
+            "yesAsk": null, "yesBid": "", "noAsk": "0", "noBid": "0.4200",
+        }));
+        assert_eq!(quoted.yes_ask, None);
+        assert_eq!(quoted.yes_bid, None);
+        assert_eq!(quoted.no_ask.as_deref(), Some("0"));
+
+        let cleared = quoted.zero_quotes_as_none();
+        assert_eq!(cleared.no_ask, None);
+        assert_eq!(cleared.no_bid.as_deref(), Some("0.4200"));
+    }
+
+    #[test]
+    fn malformed_prices_are_errors() {
+        let quoted = market(serde_json::json!({
+            "yesAsk": "0.4200", "yesBid": "n/a", "noAsk": null,
+        }));
+        assert_eq!(quoted.try_yes_ask().unwrap(), Some(0.42));
+
+        assert_eq!(quoted.yes_bid_f64(), None);
+        assert!(quoted.try_yes_bid().is_err());
+
+        assert_eq!(quoted.no_ask_f64(), None);
+        assert_eq!(quoted.try_no_ask().unwrap(), None);
+    }
+
+    fn candle(hour: i64, close: f64) -> Candlestick {
+        Candlestick {
+            time: hour * HOUR_MS,
+            open: close,
+            high: close,
+            low: close,
+            close,
+            volume: Some(10),
+        }
+    }
+
+    #[test]
+    fn filled_candles_carry_the_previous_close() {
+        let candles = [candle(4, 0.50), candle(0, 0.40), candle(1, 0.45)];
+
+        let filled = fill_gaps(&candles, 60);
+        assert_eq!(filled.len(), 5);
+        assert_eq!(filled[2].open, 0.45);
+        assert_eq!(filled[3].close, 0.45);
+        assert_eq!(filled[3].volume, Some(0));
+        assert_eq!(filled[4].close, 0.50);
+
+        // Not a usable interval, only sorted
+        let sorted = fill_gaps(&candles, 0);
+        assert_eq!(sorted.len(), 3);
+        assert_eq!(sorted[0].close, 0.40);
+    }
+
+    #[test]
+    fn price_change_tolerates_gaps() {
+        // Hourly candles with a gap between hours 2 and 20
+        let response = CandlesticksResponse {
+            candlesticks: vec![
+                candle(1, 0.50),
+                candle(2, 0.52),
+                candle(20, 0.55),
+                candle(25, 0.60),
+            ],
+        };
+        let now = 25 * 3600;
+
+        assert_eq!(response.latest_close(), Some(0.60));
+
+        // 4h ago (hour 21) falls back to the nearest candle at hour 20
+        let change = response.price_change_pct(4 * 3600, now).unwrap();
+        assert!((change - 9.0909).abs() < 1e-3);
+
+        // 72h ago is long before the first candle
+        assert_eq!(response.price_change_pct(72 * 3600, now), None);
+    }
+}
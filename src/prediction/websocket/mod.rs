@@ -25,6 +25,7 @@
 //!     }
 //!
 //!     // Cleanup
+//!     drop(stream);
 //!     unsubscribe().await;
 //!     client.shutdown().await?;
 //!
@@ -32,31 +33,40 @@
 //! }
 //! ```
 
+mod routing;
+mod streams;
 pub mod types;
 
-use std::collections::BTreeMap;
+use std::{
+    collections::{BTreeMap, VecDeque},
+    sync::{Arc, Mutex},
+};
 
 use futures_util::{
     SinkExt,
     future::BoxFuture,
     stream::{BoxStream, StreamExt},
 };
+use routing::{Router, SharedRouter, lock};
 use serde_json::Value;
 use thiserror::Error;
 use tokio::{
     net::TcpStream,
-    sync::{mpsc, oneshot},
+    sync::{broadcast, mpsc, oneshot, watch},
     task::JoinHandle,
-    time::{Duration, sleep},
+    time::{Duration, Instant, MissedTickBehavior, interval_at, timeout},
 };
+use tokio_stream::wrappers::BroadcastStream;
 use tokio_tungstenite::{
     MaybeTlsStream, WebSocketStream, connect_async,
     tungstenite::{
-        Message,
+        self, Message,
+        error::ProtocolError,
         http::Request,
         protocol::frame::{CloseFrame, coding::CloseCode},
     },
 };
+use tracing::{debug, error, warn};
 pub use types::*;
 
 /// Default WebSocket URL for the DFlow Prediction Market API
@@ -66,6 +76,81 @@ pub const DEFAULT_WS_URL: &str =
 /// Default ping interval in seconds
 pub const DEFAULT_PING_INTERVAL_SECS: u64 = 30;
 
+/// Default time without a pong before the connection is considered dead, in
+/// seconds
+pub const DEFAULT_PONG_TIMEOUT_SECS: u64 = 2 * DEFAULT_PING_INTERVAL_SECS;
+
+/// Default time to wait for a subscription acknowledgement, in seconds, when
+/// [`WsConfig::wait_for_subscribe_ack`] is set
+pub const DEFAULT_SUBSCRIBE_ACK_TIMEOUT_SECS: u64 = 10;
+
+/// Number of unrouted messages buffered for each `system_messages` stream
+const SYSTEM_MESSAGE_CAPACITY: usize = 256;
+
+// =============================================================================
+// Configuration
+// =============================================================================
+
+/// Configuration for a WebSocket connection.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use dflow_api_client::prediction::websocket::{
+///     DEFAULT_WS_URL, DflowPredictionWsClient, WsConfig,
+/// };
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// // Detect a dead connection within 15 seconds
+/// let config = WsConfig {
+///     ping_interval: Duration::from_secs(10),
+///     pong_timeout: Duration::from_secs(5),
+///     ..Default::default()
+/// };
+/// let client =
+///     DflowPredictionWsClient::connect_with_config(DEFAULT_WS_URL, &[], config)
+///         .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct WsConfig {
+    /// Whether subscribe calls wait for the server to acknowledge the
+    /// subscription (off by default).
+    ///
+    /// The ack messages (`{"type":"subscribed","channel":...}`) aren't part
+    /// of the documented protocol. When enabled, an ack or the first update
+    /// on the channel completes the subscription, and a subscription that
+    /// gets neither within `subscribe_ack_timeout` fails and is
+    /// unsubscribed again.
+    pub wait_for_subscribe_ack: bool,
+    /// How long subscribe calls wait for the server to acknowledge the
+    /// subscription before failing, if `wait_for_subscribe_ack` is set
+    pub subscribe_ack_timeout: Duration,
+    /// How often to ping the server to keep the connection alive
+    pub ping_interval: Duration,
+    /// How long the server may stay silent before the connection is
+    /// considered dead and closed. Any message counts, pongs as well as
+    /// updates, so this catches half-open connections. Should be longer
+    /// than `ping_interval`.
+    pub pong_timeout: Duration,
+}
+
+impl Default for WsConfig {
+    fn default() -> Self {
+        Self {
+            wait_for_subscribe_ack: false,
+            subscribe_ack_timeout: Duration::from_secs(
+                DEFAULT_SUBSCRIBE_ACK_TIMEOUT_SECS,
+            ),
+            ping_interval: Duration::from_secs(DEFAULT_PING_INTERVAL_SECS),
+            pong_timeout: Duration::from_secs(DEFAULT_PONG_TIMEOUT_SECS),
+        }
+    }
+}
+
 // =============================================================================
 // Error Types
 // =============================================================================
@@ -92,6 +177,52 @@ pub enum DflowWsError {
     /// Subscription failed
     #[error("Subscription failed: {0}")]
     SubscriptionFailed(String),
+
+    /// Invalid connection configuration
+    #[error("Invalid configuration: {0}")]
+    InvalidConfig(String),
+}
+
+impl DflowWsError {
+    /// Whether the error is transient, so reconnecting or repeating the
+    /// operation may succeed.
+    ///
+    /// True for dropped or failed connections (I/O errors, closed
+    /// connections, server errors during the handshake). False for errors
+    /// that repeating can't fix, such as an invalid URL, a rejected
+    /// subscription or a serialization failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dflow_api_client::prediction::websocket::DflowWsError;
+    ///
+    /// assert!(DflowWsError::ConnectionClosed("reset".into()).is_transient());
+    /// assert!(!DflowWsError::SubscriptionFailed("denied".into()).is_transient());
+    /// ```
+    pub fn is_transient(&self) -> bool {
+        match self {
+            DflowWsError::ConnectionFailed(e) => match e {
+                tungstenite::Error::ConnectionClosed
+                | tungstenite::Error::AlreadyClosed
+                | tungstenite::Error::Io(_)
+                | tungstenite::Error::Protocol(
+                    ProtocolError::ResetWithoutClosingHandshake,
+                ) => true,
+                tungstenite::Error::Http(response) => {
+                    let status = response.status();
+                    status.is_server_error() || status.as_u16() == 429
+                }
+                _ => false,
+            },
+            DflowWsError::ConnectionClosed(_) | DflowWsError::SendFailed => {
+                true
+            }
+            DflowWsError::SerializeError(_)
+            | DflowWsError::SubscriptionFailed(_)
+            | DflowWsError::InvalidConfig(_) => false,
+        }
+    }
 }
 
 /// Result type for WebSocket operations.
@@ -104,8 +235,26 @@ pub type WsResult<T> = Result<T, DflowWsError>;
 type UnsubscribeFn = Box<dyn FnOnce() -> BoxFuture<'static, ()> + Send>;
 type SubscribeResponseMsg =
     WsResult<(mpsc::UnboundedReceiver<Value>, UnsubscribeFn)>;
-type SubscribeRequestMsg =
+/// Subscribe message, whether to wait for the server ack, and response sender
+type SubscribeRequestMsg = (
+    SubscribeMessage,
+    bool,
+    oneshot::Sender<SubscribeResponseMsg>,
+);
+type PendingSubscription =
     (SubscribeMessage, oneshot::Sender<SubscribeResponseMsg>);
+/// What to unsubscribe from, and response sender
+type UnsubscribeRequestMsg = (UnsubscribeTarget, oneshot::Sender<()>);
+/// Target of an unsubscribe request.
+enum UnsubscribeTarget {
+    /// A single subscription, by id
+    Subscription(u64),
+    /// Individual tickers of a channel, across all its subscriptions
+    Tickers(Channel, Vec<String>),
+    /// A subscription whose caller stopped waiting for the ack
+    Abandoned(SubscribeMessage),
+}
+
 type SubscribeResult<'a, T> = WsResult<(BoxStream<'a, T>, UnsubscribeFn)>;
 
 // =============================================================================
@@ -136,7 +285,12 @@ type SubscribeResult<'a, T> = WsResult<(BoxStream<'a, T>, UnsubscribeFn)>;
 /// }
 /// ```
 pub struct DflowPredictionWsClient {
+    config: WsConfig,
     subscribe_sender: mpsc::UnboundedSender<SubscribeRequestMsg>,
+    unsubscribe_sender: mpsc::UnboundedSender<UnsubscribeRequestMsg>,
+    system_sender: broadcast::Sender<Value>,
+    router: SharedRouter,
+    status_receiver: watch::Receiver<ConnectionStatus>,
     shutdown_sender: Option<oneshot::Sender<()>>,
     ws_task: Option<JoinHandle<WsResult<()>>>,
 }
@@ -185,6 +339,30 @@ impl DflowPredictionWsClient {
         .await
     }
 
+    /// Connect to the DFlow WebSocket API using an API key and connection
+    /// configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - The API key for authentication
+    /// * `config` - Connection configuration, e.g. a shorter `ping_interval`
+    ///   for proxies that close idle connections
+    ///
+    /// # Returns
+    ///
+    /// A connected `DflowPredictionWsClient` ready for subscriptions.
+    pub async fn connect_with_api_key_and_config(
+        api_key: &str,
+        config: WsConfig,
+    ) -> WsResult<Self> {
+        Self::connect_with_config(
+            DEFAULT_WS_URL,
+            &[("Authorization", &format!("Bearer {}", api_key))],
+            config,
+        )
+        .await
+    }
+
     /// Connect to the DFlow WebSocket API using a custom URL and headers.
     ///
     /// # Arguments
@@ -199,6 +377,55 @@ impl DflowPredictionWsClient {
         url: &str,
         headers: &[(&str, &str)],
     ) -> WsResult<Self> {
+        Self::connect_with_config(url, headers, WsConfig::default()).await
+    }
+
+    /// Connect to the DFlow WebSocket API using a custom URL, headers and
+    /// connection configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The WebSocket URL to connect to
+    /// * `headers` - A slice of header key-value pairs to include in the connection request
+    /// * `config` - Connection configuration
+    ///
+    /// # Returns
+    ///
+    /// A connected `DflowPredictionWsClient` ready for subscriptions, or
+    /// `InvalidConfig` if `ping_interval` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use dflow_api_client::prediction::websocket::{
+    ///     DflowPredictionWsClient, DflowWsError, WsConfig,
+    /// };
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let config = WsConfig {
+    ///     ping_interval: Duration::ZERO,
+    ///     ..Default::default()
+    /// };
+    /// let result =
+    ///     DflowPredictionWsClient::connect_with_config("ws://localhost", &[], config)
+    ///         .await;
+    /// assert!(matches!(result, Err(DflowWsError::InvalidConfig(_))));
+    /// # }
+    /// ```
+    pub async fn connect_with_config(
+        url: &str,
+        headers: &[(&str, &str)],
+        config: WsConfig,
+    ) -> WsResult<Self> {
+        if config.ping_interval.is_zero() {
+            return Err(DflowWsError::InvalidConfig(
+                "ping_interval must be greater than zero".to_string(),
+            ));
+        }
+
         let mut request = Request::builder()
             .uri(url)
             .header("Host", url_host(url).unwrap_or_default())
@@ -222,17 +449,43 @@ impl DflowPredictionWsClient {
         let (ws, _response) = connect_async(request).await?;
 
         let (subscribe_sender, subscribe_receiver) = mpsc::unbounded_channel();
+        let (unsubscribe_sender, unsubscribe_receiver) =
+            mpsc::unbounded_channel();
         let (shutdown_sender, shutdown_receiver) = oneshot::channel();
+        let (system_sender, _) = broadcast::channel(SYSTEM_MESSAGE_CAPACITY);
+        let router =
+            Arc::new(Mutex::new(Router::new(unsubscribe_sender.clone())));
+
+        let (status_sender, status_receiver) =
+            watch::channel(ConnectionStatus::Connected);
 
-        let ws_task = tokio::spawn(Self::run_ws(
+        let task_router = router.clone();
+        let run = Self::run_ws(
             ws,
             subscribe_receiver,
+            unsubscribe_receiver,
             shutdown_receiver,
-            DEFAULT_PING_INTERVAL_SECS,
-        ));
+            system_sender.clone(),
+            router.clone(),
+            config.clone(),
+        );
+        let ws_task = tokio::spawn(async move {
+            let status = run.await;
+            // Publish the status before ending the streams, so consumers
+            // see it once their stream ends
+            let result = close_error(&status).map_or(Ok(()), Err);
+            let _ = status_sender.send(status);
+            lock(&task_router).close();
+            result
+        });
 
         Ok(Self {
+            config,
             subscribe_sender,
+            unsubscribe_sender,
+            system_sender,
+            router,
+            status_receiver,
             shutdown_sender: Some(shutdown_sender),
             ws_task: Some(ws_task),
         })
@@ -241,6 +494,9 @@ impl DflowPredictionWsClient {
     /// Gracefully shutdown the WebSocket connection.
     ///
     /// This will close the connection and wait for the background task to complete.
+    ///
+    /// Returns `ConnectionClosed` with the reason if the connection had
+    /// already been closed by the server or failed.
     pub async fn shutdown(mut self) -> WsResult<()> {
         // Send shutdown signal
         if let Some(sender) = self.shutdown_sender.take() {
@@ -288,6 +544,29 @@ impl DflowPredictionWsClient {
     /// A tuple containing:
     /// - A stream of `PriceUpdate` messages
     /// - An unsubscribe function to stop receiving updates
+    ///
+    /// Each call gets its own stream, so subscriptions to different tickers
+    /// on the same connection receive their updates independently.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use dflow_api_client::prediction::websocket::DflowPredictionWsClient;
+    /// use futures_util::StreamExt;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DflowPredictionWsClient::connect().await?;
+    ///
+    /// let (mut prices, unsubscribe) = client
+    ///     .prices_subscribe_tickers(vec!["KXBTC-25DEC31-T100000".to_string()])
+    ///     .await?;
+    /// while let Some(price) = prices.next().await {
+    ///     println!("{}: {:?}", price.market_ticker, price.yes_bid);
+    /// }
+    /// unsubscribe().await;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub async fn prices_subscribe_tickers(
         &self,
         tickers: Vec<String>,
@@ -299,6 +578,23 @@ impl DflowPredictionWsClient {
         .await
     }
 
+    /// Stop receiving price updates for specific market tickers.
+    ///
+    /// Other tickers on the channel keep streaming to their subscriptions.
+    /// Updates for these tickers that arrive before the server processes the
+    /// request are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `tickers` - List of market ticker IDs to unsubscribe from
+    pub async fn prices_unsubscribe_tickers(
+        &self,
+        tickers: Vec<String>,
+    ) -> WsResult<()> {
+        self.unsubscribe(UnsubscribeTarget::Tickers(Channel::Prices, tickers))
+            .await
+    }
+
     // =========================================================================
     // Trades Channel
     // =========================================================================
@@ -339,6 +635,23 @@ impl DflowPredictionWsClient {
         .await
     }
 
+    /// Stop receiving trade updates for specific market tickers.
+    ///
+    /// Other tickers on the channel keep streaming to their subscriptions.
+    /// Updates for these tickers that arrive before the server processes the
+    /// request are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `tickers` - List of market ticker IDs to unsubscribe from
+    pub async fn trades_unsubscribe_tickers(
+        &self,
+        tickers: Vec<String>,
+    ) -> WsResult<()> {
+        self.unsubscribe(UnsubscribeTarget::Tickers(Channel::Trades, tickers))
+            .await
+    }
+
     // =========================================================================
     // Orderbook Channel
     // =========================================================================
@@ -379,11 +692,317 @@ impl DflowPredictionWsClient {
         .await
     }
 
+    /// Stop receiving orderbook updates for specific market tickers.
+    ///
+    /// Other tickers on the channel keep streaming to their subscriptions.
+    /// Updates for these tickers that arrive before the server processes the
+    /// request are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `tickers` - List of market ticker IDs to unsubscribe from
+    pub async fn orderbook_unsubscribe_tickers(
+        &self,
+        tickers: Vec<String>,
+    ) -> WsResult<()> {
+        self.unsubscribe(UnsubscribeTarget::Tickers(
+            Channel::Orderbook,
+            tickers,
+        ))
+        .await
+    }
+
+    // =========================================================================
+    // Custom Subscriptions
+    // =========================================================================
+
+    /// Subscribe to a channel and receive the messages as untyped JSON.
+    ///
+    /// Skips the typed deserialization of the `*_subscribe_*` methods, which
+    /// drop messages that don't parse into the update type. Useful for
+    /// logging, debugging, or capturing fields and message kinds the crate
+    /// doesn't model yet. Waits for the server to acknowledge the
+    /// subscription like the typed methods, if
+    /// [`WsConfig::wait_for_subscribe_ack`] is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `channel` - Channel to subscribe to
+    /// * `scope` - All markets or specific tickers
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing:
+    /// - A stream of the messages as received
+    /// - An unsubscribe function to stop receiving updates
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use dflow_api_client::prediction::websocket::{
+    ///     Channel, DflowPredictionWsClient, SubscriptionScope,
+    /// };
+    /// use futures_util::StreamExt;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DflowPredictionWsClient::connect().await?;
+    ///
+    /// let (mut messages, _unsubscribe) = client
+    ///     .subscribe_raw(Channel::Prices, SubscriptionScope::All)
+    ///     .await?;
+    /// while let Some(message) = messages.next().await {
+    ///     println!("{}", message);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn subscribe_raw(
+        &self,
+        channel: Channel,
+        scope: SubscriptionScope,
+    ) -> SubscribeResult<'static, Value> {
+        let (notifications, unsubscribe) = self
+            .subscribe_values(
+                scope.subscribe_message(channel),
+                self.config.wait_for_subscribe_ack,
+            )
+            .await?;
+
+        let stream =
+            tokio_stream::wrappers::UnboundedReceiverStream::new(notifications)
+                .boxed();
+
+        Ok((stream, unsubscribe))
+    }
+
+    /// Subscribe with a raw subscription message without waiting for the
+    /// server to acknowledge it.
+    ///
+    /// With [`WsConfig::wait_for_subscribe_ack`] set, the typed
+    /// `*_subscribe_*` methods wait for the server's subscribe-ack and fail
+    /// if the subscription is rejected or not acknowledged in time. This
+    /// method always returns the stream immediately, so a silently rejected
+    /// subscription yields a stream that never receives updates.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - The subscription message to send
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing:
+    /// - A stream of messages deserialized into `T`
+    /// - An unsubscribe function to stop receiving updates
+    pub async fn subscribe_nowait<'a, T>(
+        &self,
+        msg: SubscribeMessage,
+    ) -> SubscribeResult<'a, T>
+    where
+        T: serde::de::DeserializeOwned + Send + 'a,
+    {
+        self.subscribe(msg, false).await
+    }
+
+    /// Subscribe and number every message to detect dropped or out-of-order
+    /// updates.
+    ///
+    /// Each update carries the server's `seq` field, if present. When a
+    /// `seq` differs from the one expected after the previous sequenced
+    /// message, a [`Sequenced::Gap`] is yielded before the update so the
+    /// consumer can resync its local state. Messages without `seq` are
+    /// passed through. Messages that fail to parse are logged and skipped
+    /// like on the other subscriptions, and don't show up as gaps.
+    ///
+    /// `seq` is unverified: neither the documented protocol nor captured
+    /// messages carry it. Until the server sends it, this stream never
+    /// yields a `Gap` and every update has `seq: None`. There is no
+    /// client-side fallback, as a counter of received messages can't see
+    /// the ones that never arrived.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - The subscription message to send
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing:
+    /// - A stream of sequenced updates and gap signals
+    /// - An unsubscribe function to stop receiving updates
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use dflow_api_client::prediction::websocket::{
+    ///     Channel, DflowPredictionWsClient, PriceUpdate, Sequenced,
+    ///     SubscribeMessage,
+    /// };
+    /// use futures_util::StreamExt;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DflowPredictionWsClient::connect().await?;
+    ///
+    /// let (mut stream, _unsub) = client
+    ///     .subscribe_sequenced::<PriceUpdate>(SubscribeMessage::all(
+    ///         Channel::Prices,
+    ///     ))
+    ///     .await?;
+    /// while let Some(item) = stream.next().await {
+    ///     match item {
+    ///         Sequenced::Update { update, .. } => println!("{:?}", update),
+    ///         Sequenced::Gap { expected, got } => {
+    ///             println!("Missed updates {}..{}, resyncing", expected, got)
+    ///         }
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn subscribe_sequenced<'a, T>(
+        &self,
+        msg: SubscribeMessage,
+    ) -> SubscribeResult<'a, Sequenced<T>>
+    where
+        T: serde::de::DeserializeOwned + Send + 'a,
+    {
+        let (notifications, unsubscribe) = self
+            .subscribe_values(msg, self.config.wait_for_subscribe_ack)
+            .await?;
+
+        let mut expected: Option<u64> = None;
+
+        let stream =
+            tokio_stream::wrappers::UnboundedReceiverStream::new(notifications)
+                .flat_map(move |value| {
+                    let seq = value.get("seq").and_then(Value::as_u64);
+
+                    let mut items = Vec::with_capacity(2);
+                    if let Some(seq) = seq {
+                        if let Some(expected) = expected
+                            && seq != expected
+                        {
+                            items.push(Sequenced::Gap { expected, got: seq });
+                        }
+                        expected = Some(seq + 1);
+                    }
+
+                    if let Some(update) = parse_message::<T>(value) {
+                        items.push(Sequenced::Update { seq, update });
+                    }
+
+                    futures_util::stream::iter(items)
+                })
+                .boxed();
+
+        Ok((stream, unsubscribe))
+    }
+
+    /// Stream of text messages that weren't routed to any subscription.
+    ///
+    /// Receives server announcements, maintenance notices and any message
+    /// whose `channel` matches no active subscription, which would otherwise
+    /// be dropped. Messages that aren't valid JSON are delivered as
+    /// `Value::String`. Subscription acknowledgements are not included,
+    /// except errors that name no pending subscription's channel, and normal
+    /// routing is unaffected.
+    ///
+    /// Each call returns an independent stream that only sees messages
+    /// received after it was created. A stream that falls more than 256
+    /// messages behind skips the oldest ones.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use dflow_api_client::prediction::websocket::DflowPredictionWsClient;
+    /// use futures_util::StreamExt;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DflowPredictionWsClient::connect().await?;
+    ///
+    /// let mut notices = client.system_messages();
+    /// while let Some(notice) = notices.next().await {
+    ///     println!("Server message: {}", notice);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn system_messages(&self) -> BoxStream<'static, Value> {
+        BroadcastStream::new(self.system_sender.subscribe())
+            .filter_map(|message| async move { message.ok() })
+            .boxed()
+    }
+
+    /// Watch the state of the connection.
+    ///
+    /// Subscription streams simply end when the connection closes; the
+    /// status tells why. It is set before the streams end, so it can be
+    /// read right after a stream returns `None`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use dflow_api_client::prediction::websocket::DflowPredictionWsClient;
+    /// use futures_util::StreamExt;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DflowPredictionWsClient::connect().await?;
+    ///
+    /// let (mut prices, _unsubscribe) = client.prices_subscribe_all().await?;
+    /// while let Some(price) = prices.next().await {
+    ///     println!("{:?}", price);
+    /// }
+    /// println!("connection ended: {:?}", *client.status().borrow());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn status(&self) -> watch::Receiver<ConnectionStatus> {
+        self.status_receiver.clone()
+    }
+
+    /// Snapshot of the client's subscriptions and message activity.
+    ///
+    /// Shows which channels and tickers are subscribed, how many messages
+    /// each subscription received and when, which helps diagnosing a
+    /// stream that stops receiving updates. Cheap to call: it only copies
+    /// the routing state and never waits on the connection.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use dflow_api_client::prediction::websocket::DflowPredictionWsClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DflowPredictionWsClient::connect().await?;
+    /// let (_prices, _unsub) = client.prices_subscribe_all().await?;
+    ///
+    /// let state = client.debug_state();
+    /// for sub in &state.subscriptions {
+    ///     println!(
+    ///         "#{} {:?} {:?}: {} messages, last at {:?}",
+    ///         sub.id, sub.channel, sub.tickers, sub.messages, sub.last_message_at
+    ///     );
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn debug_state(&self) -> WsDebugState {
+        let connected = self
+            .ws_task
+            .as_ref()
+            .is_some_and(|task| !task.is_finished())
+            && self.status_receiver.borrow().is_connected();
+        lock(&self.router).debug_state(connected)
+    }
+
     // =========================================================================
     // Internal Methods
     // =========================================================================
 
     /// Internal method to subscribe to a channel and return a typed stream.
+    ///
+    /// If `wait_for_subscribe_ack` is set, waits for the server to
+    /// acknowledge the subscription, returning `SubscriptionFailed` if it is
+    /// rejected and `ConnectionClosed` if no acknowledgement arrives within
+    /// the configured timeout.
     async fn subscribe_channel<'a, T>(
         &self,
         msg: SubscribeMessage,
@@ -391,52 +1010,107 @@ impl DflowPredictionWsClient {
     where
         T: serde::de::DeserializeOwned + Send + 'a,
     {
+        self.subscribe(msg, self.config.wait_for_subscribe_ack)
+            .await
+    }
+
+    async fn subscribe<'a, T>(
+        &self,
+        msg: SubscribeMessage,
+        wait_for_ack: bool,
+    ) -> SubscribeResult<'a, T>
+    where
+        T: serde::de::DeserializeOwned + Send + 'a,
+    {
+        let (notifications, unsubscribe) =
+            self.subscribe_values(msg, wait_for_ack).await?;
+
+        let stream =
+            tokio_stream::wrappers::UnboundedReceiverStream::new(notifications)
+                .filter_map(|value| async move { parse_message::<T>(value) })
+                .boxed();
+
+        Ok((stream, unsubscribe))
+    }
+
+    /// Send a subscription request and return the untyped notifications.
+    async fn subscribe_values(
+        &self,
+        msg: SubscribeMessage,
+        wait_for_ack: bool,
+    ) -> SubscribeResponseMsg {
         let (response_sender, response_receiver) = oneshot::channel();
 
         self.subscribe_sender
-            .send((msg, response_sender))
+            .send((msg.clone(), wait_for_ack, response_sender))
             .map_err(|_| DflowWsError::SendFailed)?;
 
-        let (notifications, unsubscribe) =
-            response_receiver.await.map_err(|_| {
-                DflowWsError::ConnectionClosed(
-                    "Response channel closed".to_string(),
-                )
-            })??;
-
-        let stream = tokio_stream::wrappers::UnboundedReceiverStream::new(notifications)
-            .filter_map(|value| async move {
-                match serde_json::from_value::<T>(value.clone()) {
-                    Ok(parsed) => Some(parsed),
-                    Err(e) => {
-                        eprintln!(
-                            "Failed to parse WebSocket message: {:?} for value: {:?}",
-                            e, value
-                        );
-                        None
-                    }
+        let response = if wait_for_ack {
+            match timeout(self.config.subscribe_ack_timeout, response_receiver)
+                .await
+            {
+                Ok(response) => response,
+                Err(_) => {
+                    // Drop the pending entry and the server-side
+                    // subscription
+                    let _ = self
+                        .unsubscribe(UnsubscribeTarget::Abandoned(msg))
+                        .await;
+                    return Err(DflowWsError::ConnectionClosed(
+                        "subscribe ack timeout".to_string(),
+                    ));
                 }
-            })
-            .boxed();
+            }
+        } else {
+            response_receiver.await
+        };
 
-        Ok((stream, unsubscribe))
+        response.map_err(|_| {
+            DflowWsError::ConnectionClosed(
+                "Response channel closed".to_string(),
+            )
+        })?
+    }
+
+    /// Send an unsubscribe request and wait until it has been sent.
+    async fn unsubscribe(&self, target: UnsubscribeTarget) -> WsResult<()> {
+        let (response_sender, response_receiver) = oneshot::channel();
+
+        self.unsubscribe_sender
+            .send((target, response_sender))
+            .map_err(|_| DflowWsError::SendFailed)?;
+
+        response_receiver.await.map_err(|_| {
+            DflowWsError::ConnectionClosed(
+                "Response channel closed".to_string(),
+            )
+        })
     }
 
     /// Background task that manages the WebSocket connection.
     async fn run_ws(
         mut ws: WebSocketStream<MaybeTlsStream<TcpStream>>,
         mut subscribe_receiver: mpsc::UnboundedReceiver<SubscribeRequestMsg>,
+        mut unsubscribe_receiver: mpsc::UnboundedReceiver<
+            UnsubscribeRequestMsg,
+        >,
         mut shutdown_receiver: oneshot::Receiver<()>,
-        ping_interval_secs: u64,
-    ) -> WsResult<()> {
-        // Track subscriptions by channel
-        // Key: channel name, Value: sender for notifications
-        let mut subscriptions: BTreeMap<String, mpsc::UnboundedSender<Value>> =
+        system_sender: broadcast::Sender<Value>,
+        router: SharedRouter,
+        config: WsConfig,
+    ) -> ConnectionStatus {
+        // Subscriptions waiting for a server ack, in request order per channel
+        let mut pending: BTreeMap<String, VecDeque<PendingSubscription>> =
             BTreeMap::new();
-        let (unsubscribe_sender, mut unsubscribe_receiver) =
-            mpsc::unbounded_channel::<(Channel, oneshot::Sender<()>)>();
 
-        loop {
+        let mut ping = interval_at(
+            Instant::now() + config.ping_interval,
+            config.ping_interval,
+        );
+        ping.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        let mut last_received = Instant::now();
+
+        let status = loop {
             tokio::select! {
                 // Handle shutdown signal
                 _ = &mut shutdown_receiver => {
@@ -446,21 +1120,33 @@ impl DflowPredictionWsClient {
                     };
                     let _ = ws.send(Message::Close(Some(frame))).await;
                     let _ = ws.flush().await;
-                    break;
+                    break ConnectionStatus::Shutdown;
                 }
 
                 // Send periodic ping to keep connection alive
-                _ = sleep(Duration::from_secs(ping_interval_secs)) => {
+                _ = ping.tick() => {
+                    // A half-open connection never answers, close it
+                    if last_received.elapsed() > config.pong_timeout {
+                        let silent = last_received.elapsed();
+                        warn!(?silent, "no pong received, closing connection");
+                        break ConnectionStatus::Failed(format!(
+                            "no pong received for {:?}",
+                            silent
+                        ));
+                    }
+
                     if let Err(e) = ws.send(Message::Ping(vec![])).await {
-                        eprintln!("Failed to send ping: {:?}", e);
-                        break;
+                        warn!(error = %e, "failed to send ping");
+                        break ConnectionStatus::Failed(e.to_string());
                     }
                 }
 
                 // Handle subscription requests
-                Some((subscribe_msg, response_sender)) = subscribe_receiver.recv() => {
+                Some((subscribe_msg, wait_for_ack, response_sender)) = subscribe_receiver.recv() => {
                     let channel = subscribe_msg.channel;
-                    let channel_name = channel.as_str().to_string();
+
+                    // Resubscribing resumes routing of dropped tickers
+                    lock(&router).resume(&subscribe_msg);
 
                     // Serialize and send the subscription message
                     let msg_json = match serde_json::to_string(&subscribe_msg) {
@@ -476,34 +1162,45 @@ impl DflowPredictionWsClient {
                         continue;
                     }
 
-                    // Create notification channel for this subscription
-                    let (notifications_sender, notifications_receiver) = mpsc::unbounded_channel();
-
-                    // Store the sender for routing messages
-                    subscriptions.insert(channel_name.clone(), notifications_sender);
-
-                    // Create unsubscribe function
-                    let unsub_sender = unsubscribe_sender.clone();
-                    let unsubscribe: UnsubscribeFn = Box::new(move || {
-                        Box::pin(async move {
-                            let (response_sender, response_receiver) = oneshot::channel();
-                            if unsub_sender.send((channel, response_sender)).is_ok() {
-                                let _ = response_receiver.await;
-                            }
-                        })
-                    });
-
-                    let _ = response_sender.send(Ok((notifications_receiver, unsubscribe)));
+                    if wait_for_ack {
+                        // Respond once the server acknowledges the subscription
+                        pending
+                            .entry(channel.as_str().to_string())
+                            .or_default()
+                            .push_back((subscribe_msg, response_sender));
+                    } else {
+                        let subscription =
+                            lock(&router).register(&subscribe_msg);
+                        let _ = response_sender.send(Ok(subscription));
+                    }
                 }
 
                 // Handle unsubscribe requests
-                Some((channel, response_sender)) = unsubscribe_receiver.recv() => {
-                    let channel_name = channel.as_str().to_string();
-                    subscriptions.remove(&channel_name);
+                Some((target, response_sender)) = unsubscribe_receiver.recv() => {
+                    let unsub_msg = match target {
+                        UnsubscribeTarget::Subscription(id) => lock(&router).remove(id),
+                        UnsubscribeTarget::Tickers(channel, tickers) => {
+                            lock(&router).drop_tickers(channel, &tickers);
+                            Some(SubscribeMessage::unsubscribe_tickers(channel, tickers))
+                        }
+                        UnsubscribeTarget::Abandoned(msg) => {
+                            let queue = pending
+                                .entry(msg.channel.as_str().to_string())
+                                .or_default();
+                            queue.retain(|(_, sender)| !sender.is_closed());
+                            // Subscriptions still waiting need the channel
+                            if queue.is_empty() {
+                                lock(&router).release(&msg)
+                            } else {
+                                None
+                            }
+                        }
+                    };
 
                     // Send unsubscribe message to server
-                    let unsub_msg = SubscribeMessage::unsubscribe_all(channel);
-                    if let Ok(msg_json) = serde_json::to_string(&unsub_msg) {
+                    if let Some(unsub_msg) = unsub_msg
+                        && let Ok(msg_json) = serde_json::to_string(&unsub_msg)
+                    {
                         let _ = ws.send(Message::Text(msg_json)).await;
                     }
 
@@ -515,22 +1212,82 @@ impl DflowPredictionWsClient {
                     let msg = match next_msg {
                         Some(Ok(msg)) => msg,
                         Some(Err(e)) => {
-                            eprintln!("WebSocket error: {:?}", e);
-                            break;
+                            error!(error = %e, "WebSocket error");
+                            break ConnectionStatus::Failed(e.to_string());
+                        }
+                        None => {
+                            debug!("WebSocket stream ended");
+                            break ConnectionStatus::ClosedByServer(None);
                         }
-                        None => break,
                     };
+                    last_received = Instant::now();
 
                     match msg {
                         Message::Text(text) => {
-                            // Parse to determine channel
-                            if let Ok(raw) = serde_json::from_str::<RawMessage>(&text) {
-                                if let Some(sender) = subscriptions.get(&raw.channel) {
-                                    if let Ok(value) = serde_json::from_str::<Value>(&text) {
-                                        let _ = sender.send(value);
+                            // Resolve pending subscriptions on ack/nack
+                            if let Ok(ack) = serde_json::from_str::<SubscriptionAck>(&text) {
+                                match ack {
+                                    SubscriptionAck::Subscribed { channel } => {
+                                        if let Some(pending_sub) = pending
+                                            .get_mut(channel.as_str())
+                                            .and_then(VecDeque::pop_front)
+                                        {
+                                            lock(&router).confirm(pending_sub);
+                                        }
                                     }
+                                    SubscriptionAck::Error { channel, message } => {
+                                        // Only errors naming a channel can be
+                                        // matched to a subscription
+                                        let rejected = channel
+                                            .and_then(|channel| pending.get_mut(channel.as_str()))
+                                            .and_then(VecDeque::pop_front);
+                                        let Some((_, response_sender)) = rejected else {
+                                            if let Ok(value) = serde_json::from_str(&text) {
+                                                let _ = system_sender.send(value);
+                                            }
+                                            continue;
+                                        };
+                                        let reason = message.unwrap_or_else(|| {
+                                            "rejected by server".to_string()
+                                        });
+                                        warn!(
+                                            channel = ?channel,
+                                            %reason,
+                                            "subscription rejected"
+                                        );
+                                        let _ = response_sender
+                                            .send(Err(DflowWsError::SubscriptionFailed(reason)));
+                                    }
+                                    SubscriptionAck::Unsubscribed { .. } => {}
+                                }
+                                continue;
+                            }
+
+                            // Parse to determine channel
+                            let Ok(raw) = serde_json::from_str::<RawMessage>(&text) else {
+                                let _ = system_sender.send(
+                                    serde_json::from_str(&text)
+                                        .unwrap_or(Value::String(text)),
+                                );
+                                continue;
+                            };
+
+                            // Data on a channel implicitly acknowledges its
+                            // pending subscriptions
+                            if let Some(queue) = pending.get_mut(&raw.channel) {
+                                while let Some(pending_sub) = queue.pop_front() {
+                                    lock(&router).confirm(pending_sub);
                                 }
                             }
+
+                            let Ok(value) = serde_json::from_str::<Value>(&text) else {
+                                continue;
+                            };
+                            // Fan out by ticker to every interested subscription
+                            let ticker = raw.market_ticker.as_deref();
+                            if !lock(&router).dispatch(&raw.channel, ticker, &value) {
+                                let _ = system_sender.send(value);
+                            }
                         }
                         Message::Ping(data) => {
                             let _ = ws.send(Message::Pong(data)).await;
@@ -538,16 +1295,28 @@ impl DflowPredictionWsClient {
                         Message::Pong(_) => {
                             // Connection is alive
                         }
-                        Message::Close(_) => {
-                            break;
+                        Message::Close(frame) => {
+                            let reason = frame
+                                .map(|frame| frame.reason.into_owned())
+                                .filter(|reason| !reason.is_empty());
+                            debug!(?reason, "connection closed by server");
+                            break ConnectionStatus::ClosedByServer(reason);
                         }
                         _ => {}
                     }
                 }
             }
+        };
+
+        // Subscriptions still waiting for an ack will never get one
+        for (_, response_sender) in pending.into_values().flatten() {
+            let error = close_error(&status).unwrap_or_else(|| {
+                DflowWsError::ConnectionClosed("Client shutdown".to_string())
+            });
+            let _ = response_sender.send(Err(error));
         }
 
-        Ok(())
+        status
     }
 }
 
@@ -560,6 +1329,41 @@ impl Drop for DflowPredictionWsClient {
     }
 }
 
+/// Error describing how a connection ended, `None` if the client shut it
+/// down.
+fn close_error(status: &ConnectionStatus) -> Option<DflowWsError> {
+    let reason = match status {
+        ConnectionStatus::Connected | ConnectionStatus::Shutdown => {
+            return None;
+        }
+        ConnectionStatus::ClosedByServer(reason) => reason
+            .clone()
+            .unwrap_or_else(|| "Closed by server".to_string()),
+        ConnectionStatus::Failed(reason) => reason.clone(),
+    };
+    Some(DflowWsError::ConnectionClosed(reason))
+}
+
+/// Parse a notification into a typed message, logging and dropping it on
+/// failure.
+fn parse_message<T: serde::de::DeserializeOwned>(value: Value) -> Option<T> {
+    match serde_json::from_value::<T>(value.clone()) {
+        Ok(parsed) => Some(parsed),
+        Err(e) => {
+            let channel = value.get("channel").and_then(|v| v.as_str());
+            let ticker = value.get("market_ticker").and_then(|v| v.as_str());
+            warn!(
+                channel,
+                ticker,
+                error = %e,
+                message = %value,
+                "failed to parse WebSocket message"
+            );
+            None
+        }
+    }
+}
+
 /// Extract the host from a URL string.
 fn url_host(url: &str) -> Option<&str> {
     let without_scheme = url
@@ -570,3 +1374,37 @@ fn url_host(url: &str) -> Option<&str> {
 
     without_scheme.split('/').next()
 }
+
+#[cfg(test)]
+mod tests {
+    use tungstenite::error::UrlError;
+
+    use super::*;
+
+    #[test]
+    fn dropped_connections_are_transient() {
+        assert!(DflowWsError::ConnectionClosed("reset".into()).is_transient());
+        assert!(DflowWsError::SendFailed.is_transient());
+        assert!(
+            DflowWsError::ConnectionFailed(
+                tungstenite::Error::ConnectionClosed
+            )
+            .is_transient()
+        );
+    }
+
+    #[test]
+    fn unfixable_errors_are_not_transient() {
+        assert!(
+            !DflowWsError::SubscriptionFailed("denied".into()).is_transient()
+        );
+        assert!(
+            !DflowWsError::ConnectionFailed(tungstenite::Error::Url(
+                UrlError::NoHostName
+            ))
+            .is_transient()
+        );
+        let error = serde_json::from_str::<u8>("x").unwrap_err();
+        assert!(!DflowWsError::SerializeError(error).is_transient());
+    }
+}
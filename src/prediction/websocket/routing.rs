@@ -0,0 +1,291 @@
+//! Routing of incoming WebSocket messages to subscriptions.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::SystemTime,
+};
+
+use serde_json::Value;
+use tokio::sync::{mpsc, oneshot};
+
+use super::{
+    Channel, PendingSubscription, SubscribeMessage, SubscriptionState,
+    UnsubscribeFn, UnsubscribeRequestMsg, UnsubscribeTarget, WsDebugState,
+};
+
+/// Router shared between the connection task and the client.
+pub(super) type SharedRouter = Arc<Mutex<Router>>;
+
+/// Lock a shared router.
+///
+/// The lock is never held across an await, so it is only ever contended
+/// briefly by `debug_state`.
+pub(super) fn lock(router: &SharedRouter) -> MutexGuard<'_, Router> {
+    router.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// A single subscription and the markets it receives updates for.
+struct Route {
+    channel: Channel,
+    /// Subscribed tickers (all markets on the channel if `None`)
+    tickers: Option<BTreeSet<String>>,
+    sender: mpsc::UnboundedSender<Value>,
+    /// Number of messages routed to this subscription
+    messages: u64,
+    last_message_at: Option<SystemTime>,
+}
+
+impl Route {
+    /// Whether an update for `ticker` belongs to this subscription.
+    ///
+    /// Messages without a ticker go to every subscription on the channel.
+    fn covers(&self, ticker: Option<&str>) -> bool {
+        match (&self.tickers, ticker) {
+            (Some(tickers), Some(ticker)) => tickers.contains(ticker),
+            _ => true,
+        }
+    }
+}
+
+/// Subscriptions of a connection, keyed by subscription id.
+///
+/// Every subscribe call gets its own id and stream, so several subscriptions
+/// on the same channel receive updates independently.
+pub(super) struct Router {
+    routes: BTreeMap<u64, Route>,
+    next_id: u64,
+    /// Tickers unsubscribed individually, by channel name, whose updates
+    /// are dropped
+    dropped: BTreeMap<String, BTreeSet<String>>,
+    /// Number of channel messages received
+    messages_received: u64,
+    last_message_at: Option<SystemTime>,
+    /// Sender for the unsubscribe functions of registered subscriptions
+    unsubscribe_sender: mpsc::UnboundedSender<UnsubscribeRequestMsg>,
+}
+
+impl Router {
+    pub(super) fn new(
+        unsubscribe_sender: mpsc::UnboundedSender<UnsubscribeRequestMsg>,
+    ) -> Self {
+        Self {
+            routes: BTreeMap::new(),
+            next_id: 0,
+            dropped: BTreeMap::new(),
+            messages_received: 0,
+            last_message_at: None,
+            unsubscribe_sender,
+        }
+    }
+
+    /// Register a subscription and build its unsubscribe function.
+    pub(super) fn register(
+        &mut self,
+        msg: &SubscribeMessage,
+    ) -> (mpsc::UnboundedReceiver<Value>, UnsubscribeFn) {
+        let (sender, receiver) = mpsc::unbounded_channel();
+
+        let id = self.next_id;
+        self.next_id += 1;
+        self.routes.insert(
+            id,
+            Route {
+                channel: msg.channel,
+                tickers: msg
+                    .tickers
+                    .as_ref()
+                    .map(|tickers| tickers.iter().cloned().collect()),
+                sender,
+                messages: 0,
+                last_message_at: None,
+            },
+        );
+
+        let unsub_sender = self.unsubscribe_sender.clone();
+        let unsubscribe: UnsubscribeFn = Box::new(move || {
+            Box::pin(async move {
+                let (response_sender, response_receiver) = oneshot::channel();
+                let target = UnsubscribeTarget::Subscription(id);
+                if unsub_sender.send((target, response_sender)).is_ok() {
+                    let _ = response_receiver.await;
+                }
+            })
+        });
+
+        (receiver, unsubscribe)
+    }
+
+    /// Complete a pending subscription once the server has acknowledged it.
+    pub(super) fn confirm(
+        &mut self,
+        (msg, response_sender): PendingSubscription,
+    ) {
+        // The caller gave up waiting (ack timeout), nothing to route to
+        if response_sender.is_closed() {
+            return;
+        }
+
+        let subscription = self.register(&msg);
+        let _ = response_sender.send(Ok(subscription));
+    }
+
+    /// Resume routing of tickers that a new subscription covers again.
+    pub(super) fn resume(&mut self, msg: &SubscribeMessage) {
+        let Some(dropped) = self.dropped.get_mut(msg.channel.as_str()) else {
+            return;
+        };
+        match &msg.tickers {
+            Some(tickers) => {
+                for ticker in tickers {
+                    dropped.remove(ticker);
+                }
+            }
+            None => dropped.clear(),
+        }
+    }
+
+    /// Stop routing updates for the given tickers on a channel.
+    pub(super) fn drop_tickers(
+        &mut self,
+        channel: Channel,
+        tickers: &[String],
+    ) {
+        self.dropped
+            .entry(channel.as_str().to_string())
+            .or_default()
+            .extend(tickers.iter().cloned());
+    }
+
+    /// Remove a subscription.
+    ///
+    /// Returns the unsubscribe message for the server, covering only the
+    /// markets no other subscription still needs. When an all-markets
+    /// subscription is removed while ticker subscriptions remain on the
+    /// channel, the server subscription is kept and surplus updates are
+    /// dropped locally.
+    pub(super) fn remove(&mut self, id: u64) -> Option<SubscribeMessage> {
+        let route = self.routes.remove(&id)?;
+        self.unsubscribe_message(route.channel, route.tickers)
+    }
+
+    /// Unsubscribe message for a subscription that was never registered
+    /// (its caller gave up waiting for the ack), covering only the markets
+    /// no registered subscription needs.
+    pub(super) fn release(
+        &mut self,
+        msg: &SubscribeMessage,
+    ) -> Option<SubscribeMessage> {
+        let tickers = msg
+            .tickers
+            .as_ref()
+            .map(|tickers| tickers.iter().cloned().collect());
+        self.unsubscribe_message(msg.channel, tickers)
+    }
+
+    /// Unsubscribe message for the markets of a removed subscription.
+    fn unsubscribe_message(
+        &mut self,
+        channel: Channel,
+        tickers: Option<BTreeSet<String>>,
+    ) -> Option<SubscribeMessage> {
+        let remaining: Vec<&Route> = self
+            .routes
+            .values()
+            .filter(|other| other.channel == channel)
+            .collect();
+
+        if remaining.is_empty() {
+            self.dropped.remove(channel.as_str());
+            return Some(SubscribeMessage::unsubscribe_all(channel));
+        }
+
+        let tickers = tickers?;
+        if remaining.iter().any(|other| other.tickers.is_none()) {
+            return None;
+        }
+        let orphaned: Vec<String> = tickers
+            .into_iter()
+            .filter(|ticker| {
+                !remaining.iter().any(|other| other.covers(Some(ticker)))
+            })
+            .collect();
+
+        (!orphaned.is_empty())
+            .then(|| SubscribeMessage::unsubscribe_tickers(channel, orphaned))
+    }
+
+    /// Send a message to every subscription interested in it.
+    ///
+    /// Returns `false` if there is no subscription on the channel at all.
+    pub(super) fn dispatch(
+        &mut self,
+        channel: &str,
+        ticker: Option<&str>,
+        value: &Value,
+    ) -> bool {
+        let now = SystemTime::now();
+        self.messages_received += 1;
+        self.last_message_at = Some(now);
+
+        if let Some(ticker) = ticker
+            && self
+                .dropped
+                .get(channel)
+                .is_some_and(|dropped| dropped.contains(ticker))
+        {
+            return true;
+        }
+
+        let mut routed = false;
+        for route in self
+            .routes
+            .values_mut()
+            .filter(|route| route.channel.as_str() == channel)
+        {
+            routed = true;
+            if route.covers(ticker) {
+                route.messages += 1;
+                route.last_message_at = Some(now);
+                let _ = route.sender.send(value.clone());
+            }
+        }
+        routed
+    }
+
+    /// Remove all subscriptions, ending their streams.
+    pub(super) fn close(&mut self) {
+        self.routes.clear();
+    }
+
+    /// Snapshot of the subscriptions and their activity.
+    pub(super) fn debug_state(&self, connected: bool) -> WsDebugState {
+        WsDebugState {
+            connected,
+            subscriptions: self
+                .routes
+                .iter()
+                .map(|(id, route)| SubscriptionState {
+                    id: *id,
+                    channel: route.channel,
+                    tickers: route
+                        .tickers
+                        .as_ref()
+                        .map(|tickers| tickers.iter().cloned().collect()),
+                    messages: route.messages,
+                    last_message_at: route.last_message_at,
+                })
+                .collect(),
+            dropped_tickers: self
+                .dropped
+                .iter()
+                .filter(|(_, tickers)| !tickers.is_empty())
+                .map(|(channel, tickers)| {
+                    (channel.clone(), tickers.iter().cloned().collect())
+                })
+                .collect(),
+            messages_received: self.messages_received,
+            last_message_at: self.last_message_at,
+        }
+    }
+}
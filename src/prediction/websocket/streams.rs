@@ -0,0 +1,390 @@
+//! Derived streams built on top of the channel subscriptions.
+
+use std::{
+    collections::{BTreeMap, VecDeque},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use futures_util::{
+    FutureExt,
+    stream::{self, BoxStream, StreamExt},
+};
+use tokio::time::{Duration, Interval, MissedTickBehavior, interval};
+
+use super::{
+    Channel, DflowPredictionWsClient, OrderbookUpdate, PriceUpdate,
+    SubscribeResult, SubscriptionScope, TradeUpdate, UnsubscribeFn,
+};
+use crate::{
+    common::DflowError,
+    prediction::{Candlestick, DflowPredictionApiClient, Orderbook},
+};
+
+impl DflowPredictionWsClient {
+    /// Stream candlesticks for a market built from its live trades.
+    ///
+    /// Subscribes to the trades channel for `ticker` and aggregates trades
+    /// into OHLCV buckets of `interval_secs`, aligned to the Unix epoch.
+    /// Prices are the YES price in dollars and volume is the number of
+    /// contracts traded.
+    ///
+    /// Only finalized candlesticks are emitted. A bucket is finalized when a
+    /// trade for a later bucket arrives, or when the wall clock passes the
+    /// end of the bucket (checked every `interval_secs`). Intervals without
+    /// trades produce no candlestick, and trades arriving for a bucket that
+    /// was already emitted are ignored. The in-progress bucket is discarded
+    /// when the trades stream ends.
+    ///
+    /// # Arguments
+    ///
+    /// * `ticker` - Market ticker ID
+    /// * `interval_secs` - Candlestick length in seconds
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing:
+    /// - A stream of finalized `Candlestick`s
+    /// - An unsubscribe function to stop receiving updates
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use dflow_api_client::prediction::websocket::DflowPredictionWsClient;
+    /// use futures_util::StreamExt;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DflowPredictionWsClient::connect().await?;
+    ///
+    /// let (mut candles, _unsub) = client
+    ///     .candles_from_trades_stream("MARKET_TICKER".to_string(), 60)
+    ///     .await?;
+    /// while let Some(candle) = candles.next().await {
+    ///     println!("{} close: {}", candle.time, candle.close);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn candles_from_trades_stream(
+        &self,
+        ticker: String,
+        interval_secs: u64,
+    ) -> SubscribeResult<'_, Candlestick> {
+        let (trades, unsubscribe) =
+            self.trades_subscribe_tickers(vec![ticker]).await?;
+
+        let interval_secs = interval_secs.max(1);
+        let mut timer = interval(Duration::from_secs(interval_secs));
+        timer.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        let aggregator = CandleAggregator {
+            trades,
+            timer,
+            interval_ms: interval_secs as i64 * 1000,
+            current: None,
+            last_emitted: None,
+        };
+
+        let stream = stream::unfold(aggregator, |mut aggregator| async move {
+            let candle = aggregator.next_candle().await?;
+            Some((candle, aggregator))
+        })
+        .boxed();
+
+        Ok((stream, unsubscribe))
+    }
+
+    /// Subscribe to price updates, emitting at most one update per ticker
+    /// per `interval`.
+    ///
+    /// Updates are coalesced per ticker: on every tick of `interval` the
+    /// latest update received for each ticker since the previous tick is
+    /// emitted (in ticker order). Intermediate updates within a window are
+    /// dropped, so consumers always see the latest state without being
+    /// overwhelmed during volatile periods. Pending updates are flushed when
+    /// the underlying stream ends.
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - Markets to subscribe to
+    /// * `interval` - Length of each coalescing window
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing:
+    /// - A stream of throttled `PriceUpdate` messages
+    /// - An unsubscribe function to stop receiving updates
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use dflow_api_client::prediction::websocket::{
+    ///     DflowPredictionWsClient, SubscriptionScope,
+    /// };
+    /// use futures_util::StreamExt;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DflowPredictionWsClient::connect().await?;
+    ///
+    /// let (mut prices, _unsub) = client
+    ///     .prices_subscribe_throttled(
+    ///         SubscriptionScope::All,
+    ///         Duration::from_millis(250),
+    ///     )
+    ///     .await?;
+    /// while let Some(update) = prices.next().await {
+    ///     println!("{}: {:?}", update.market_ticker, update.yes_bid);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn prices_subscribe_throttled(
+        &self,
+        scope: SubscriptionScope,
+        interval: Duration,
+    ) -> SubscribeResult<'_, PriceUpdate> {
+        let (updates, unsubscribe) = self
+            .subscribe_channel(scope.subscribe_message(Channel::Prices))
+            .await?;
+
+        let mut timer =
+            tokio::time::interval(interval.max(Duration::from_millis(1)));
+        timer.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        let throttle = PriceThrottle {
+            updates: Some(updates),
+            timer,
+            latest: BTreeMap::new(),
+            ready: VecDeque::new(),
+        };
+
+        let stream = stream::unfold(throttle, |mut throttle| async move {
+            let update = throttle.next_update().await?;
+            Some((update, throttle))
+        })
+        .boxed();
+
+        Ok((stream, unsubscribe))
+    }
+
+    /// Subscribe to a market's orderbook and fetch a REST snapshot of it.
+    ///
+    /// Subscribes first and only then fetches the snapshot with `rest`, so
+    /// no update is missed between the two. Updates received while the
+    /// snapshot was in flight are returned separately from the live stream.
+    /// The REST snapshot carries no sequence number or timestamp, so it
+    /// can't be ordered against them: each may be older or newer than the
+    /// snapshot.
+    ///
+    /// If the snapshot fails, the subscription is removed again.
+    ///
+    /// # Arguments
+    ///
+    /// * `rest` - REST client to fetch the snapshot with
+    /// * `ticker` - Market ticker ID
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing:
+    /// - The REST orderbook snapshot
+    /// - The updates received while the snapshot was fetched, oldest first
+    /// - A stream of the `OrderbookUpdate` messages received afterwards
+    /// - An unsubscribe function to stop receiving updates
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use dflow_api_client::prediction::{
+    ///     DepthBook, DflowPredictionApiClient,
+    ///     websocket::DflowPredictionWsClient,
+    /// };
+    /// use futures_util::StreamExt;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let rest = DflowPredictionApiClient::with_default_url("api-key".to_string());
+    /// let ws = DflowPredictionWsClient::connect().await?;
+    ///
+    /// let (snapshot, buffered, mut updates, _unsub) = ws
+    ///     .orderbook_subscribe_with_snapshot(&rest, "MARKET_TICKER".to_string())
+    ///     .await?;
+    /// let book = DepthBook::from(snapshot);
+    /// println!("{} updates arrived during the snapshot", buffered.len());
+    /// println!("Best YES bid: {:?}", book.yes_bids.first());
+    /// while let Some(update) = updates.next().await {
+    ///     let book = DepthBook::from(update).sorted();
+    ///     println!("Best YES bid: {:?}", book.yes_bids.first());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn orderbook_subscribe_with_snapshot(
+        &self,
+        rest: &DflowPredictionApiClient,
+        ticker: String,
+    ) -> Result<
+        (
+            Orderbook,
+            Vec<OrderbookUpdate>,
+            BoxStream<'_, OrderbookUpdate>,
+            UnsubscribeFn,
+        ),
+        DflowError,
+    > {
+        let (mut updates, unsubscribe) = self
+            .orderbook_subscribe_tickers(vec![ticker.clone()])
+            .await?;
+
+        let snapshot = match rest.get_orderbook(&ticker).await {
+            Ok(snapshot) => snapshot,
+            Err(e) => {
+                unsubscribe().await;
+                return Err(e.into());
+            }
+        };
+
+        // Everything already delivered arrived before the snapshot did
+        let mut buffered = Vec::new();
+        while let Some(Some(update)) = updates.next().now_or_never() {
+            buffered.push(update);
+        }
+
+        Ok((snapshot, buffered, updates, unsubscribe))
+    }
+}
+
+/// State for coalescing price updates per ticker.
+struct PriceThrottle<'a> {
+    /// Underlying updates, `None` once the stream has ended
+    updates: Option<BoxStream<'a, PriceUpdate>>,
+    timer: Interval,
+    /// Latest update per ticker in the current window
+    latest: BTreeMap<String, PriceUpdate>,
+    /// Updates flushed from a finished window, waiting to be emitted
+    ready: VecDeque<PriceUpdate>,
+}
+
+impl PriceThrottle<'_> {
+    /// Wait for the next update to emit.
+    ///
+    /// Returns `None` once the underlying stream has ended and all pending
+    /// updates were emitted.
+    async fn next_update(&mut self) -> Option<PriceUpdate> {
+        loop {
+            if let Some(update) = self.ready.pop_front() {
+                return Some(update);
+            }
+
+            let updates = self.updates.as_mut()?;
+            tokio::select! {
+                update = updates.next() => match update {
+                    Some(update) => {
+                        self.latest
+                            .insert(update.market_ticker.clone(), update);
+                    }
+                    None => {
+                        self.updates = None;
+                        self.flush();
+                    }
+                },
+                _ = self.timer.tick() => self.flush(),
+            }
+        }
+    }
+
+    /// Move the latest update of every ticker to the ready queue.
+    fn flush(&mut self) {
+        self.ready
+            .extend(std::mem::take(&mut self.latest).into_values());
+    }
+}
+
+/// State for aggregating trades into candlesticks.
+struct CandleAggregator<'a> {
+    trades: BoxStream<'a, TradeUpdate>,
+    timer: Interval,
+    interval_ms: i64,
+    /// Bucket currently being built
+    current: Option<Candlestick>,
+    /// Start time of the last emitted bucket
+    last_emitted: Option<i64>,
+}
+
+impl CandleAggregator<'_> {
+    /// Wait for the next finalized candlestick.
+    ///
+    /// Returns `None` once the trades stream has ended.
+    async fn next_candle(&mut self) -> Option<Candlestick> {
+        loop {
+            tokio::select! {
+                trade = self.trades.next() => {
+                    let finished = self.add_trade(&trade?);
+                    if finished.is_some() {
+                        return finished;
+                    }
+                }
+                _ = self.timer.tick() => {
+                    let now_ms = SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .map(|d| d.as_millis() as i64)
+                        .unwrap_or_default();
+
+                    if self
+                        .current
+                        .as_ref()
+                        .is_some_and(|c| now_ms >= c.time + self.interval_ms)
+                    {
+                        return self.finish_current();
+                    }
+                }
+            }
+        }
+    }
+
+    /// Add a trade to its bucket, returning the previous bucket if the trade
+    /// started a new one.
+    fn add_trade(&mut self, trade: &TradeUpdate) -> Option<Candlestick> {
+        let bucket = trade.created_time
+            - trade.created_time.rem_euclid(self.interval_ms);
+        if self.last_emitted.is_some_and(|last| bucket <= last) {
+            return None;
+        }
+
+        let price = trade
+            .yes_price_dollars
+            .parse::<f64>()
+            .unwrap_or(trade.yes_price as f64 / 100.0);
+
+        match &mut self.current {
+            Some(candle) if candle.time == bucket => {
+                candle.high = candle.high.max(price);
+                candle.low = candle.low.min(price);
+                candle.close = price;
+                candle.volume =
+                    Some(candle.volume.unwrap_or_default() + trade.count);
+                None
+            }
+            // Late trade for a bucket before the current one
+            Some(candle) if bucket < candle.time => None,
+            _ => {
+                let finished = self.finish_current();
+                self.current = Some(Candlestick {
+                    time: bucket,
+                    open: price,
+                    high: price,
+                    low: price,
+                    close: price,
+                    volume: Some(trade.count),
+                });
+                finished
+            }
+        }
+    }
+
+    /// Take the current bucket as a finalized candlestick.
+    fn finish_current(&mut self) -> Option<Candlestick> {
+        let candle = self.current.take()?;
+        self.last_emitted = Some(candle.time);
+        Some(candle)
+    }
+}
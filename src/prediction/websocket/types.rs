@@ -1,9 +1,11 @@
 //! WebSocket message types for the DFlow Prediction Market API.
 
-use std::collections::HashMap;
+use std::{collections::HashMap, time::SystemTime};
 
 use serde::{Deserialize, Serialize};
 
+use crate::prediction::{DepthBook, Side};
+
 // =============================================================================
 // Channel Types
 // =============================================================================
@@ -101,6 +103,27 @@ impl SubscribeMessage {
     }
 }
 
+/// Which markets a subscription covers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubscriptionScope {
+    /// All markets on the channel
+    All,
+    /// Only the given market tickers
+    Tickers(Vec<String>),
+}
+
+impl SubscriptionScope {
+    /// Create the subscription message for this scope on a channel.
+    pub fn subscribe_message(&self, channel: Channel) -> SubscribeMessage {
+        match self {
+            SubscriptionScope::All => SubscribeMessage::all(channel),
+            SubscriptionScope::Tickers(tickers) => {
+                SubscribeMessage::tickers(channel, tickers.clone())
+            }
+        }
+    }
+}
+
 // =============================================================================
 // Response Message Types (Server -> Client)
 // =============================================================================
@@ -129,6 +152,10 @@ pub struct PriceUpdate {
     /// Best ask price for NO outcome (may be null)
     #[serde(default)]
     pub no_ask: Option<String>,
+    /// Server sequence number, if sent. Unverified: neither the documented
+    /// protocol nor captured messages carry it, so this is usually `None`.
+    #[serde(default)]
+    pub seq: Option<u64>,
 }
 
 /// Trade update message from the trades channel.
@@ -157,10 +184,22 @@ pub struct TradeUpdate {
     pub yes_price_dollars: String,
     /// NO price formatted in dollars
     pub no_price_dollars: String,
-    /// Side of the taker ("yes" or "no")
-    pub taker_side: String,
+    /// Side of the taker
+    pub taker_side: Side,
     /// Trade creation time (Unix timestamp in milliseconds)
     pub created_time: i64,
+    /// Server sequence number, if sent. Unverified: neither the documented
+    /// protocol nor captured messages carry it, so this is usually `None`.
+    #[serde(default)]
+    pub seq: Option<u64>,
+}
+
+#[cfg(feature = "chrono")]
+impl TradeUpdate {
+    /// Trade creation time (`created_time`, milliseconds).
+    pub fn created_datetime(&self) -> chrono::DateTime<chrono::Utc> {
+        crate::prediction::time::datetime_from_millis(self.created_time)
+    }
 }
 
 /// Orderbook update message from the orderbook channel.
@@ -181,6 +220,77 @@ pub struct OrderbookUpdate {
     /// Map of price (string) to quantity for NO outcome bids
     #[serde(default)]
     pub no_bids: HashMap<String, i64>,
+    /// Server sequence number, if sent. Unverified: neither the documented
+    /// protocol nor captured messages carry it, so this is usually `None`.
+    #[serde(default)]
+    pub seq: Option<u64>,
+}
+
+/// Levels with unparseable prices are skipped. The update carries no asks,
+/// so the ask sides are empty.
+///
+/// # Example
+///
+/// ```
+/// use dflow_api_client::prediction::{DepthBook, websocket::OrderbookUpdate};
+///
+/// let update: OrderbookUpdate = serde_json::from_value(serde_json::json!({
+///     "channel": "orderbook",
+///     "type": "orderbook",
+///     "market_ticker": "M",
+///     "yes_bids": { "0.40": 10, "0.45": 5, "bad": 1 },
+///     "no_bids": { "0.50": 7 },
+/// }))
+/// .unwrap();
+///
+/// let depth = DepthBook::from(&update);
+/// assert_eq!(depth.yes_bids, [(0.45, 5), (0.40, 10)]);
+/// assert_eq!(depth.no_bids, [(0.50, 7)]);
+/// assert!(depth.yes_asks.is_empty() && depth.no_asks.is_empty());
+/// ```
+impl From<&OrderbookUpdate> for DepthBook {
+    fn from(update: &OrderbookUpdate) -> Self {
+        let levels = |levels: &HashMap<String, i64>| {
+            levels
+                .iter()
+                .filter_map(|(price, quantity)| {
+                    Some((price.trim().parse().ok()?, *quantity))
+                })
+                .collect()
+        };
+        DepthBook {
+            ticker: update.market_ticker.clone(),
+            yes_bids: levels(&update.yes_bids),
+            no_bids: levels(&update.no_bids),
+            ..Default::default()
+        }
+        .sorted()
+    }
+}
+
+impl From<OrderbookUpdate> for DepthBook {
+    fn from(update: OrderbookUpdate) -> Self {
+        DepthBook::from(&update)
+    }
+}
+
+/// Server acknowledgement of a subscribe or unsubscribe request.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum SubscriptionAck {
+    /// The subscription was accepted
+    Subscribed { channel: Channel },
+    /// The unsubscription was accepted
+    Unsubscribed { channel: Channel },
+    /// The request was rejected
+    Error {
+        /// Channel the rejected request was for (if reported)
+        #[serde(default)]
+        channel: Option<Channel>,
+        /// Reason for the rejection (if reported)
+        #[serde(default)]
+        message: Option<String>,
+    },
 }
 
 /// A unified WebSocket message that can be any of the channel-specific updates.
@@ -194,8 +304,93 @@ pub enum WsMessage {
     Orderbook(OrderbookUpdate),
 }
 
+/// A numbered message from a sequenced subscription.
+#[derive(Debug, Clone)]
+pub enum Sequenced<T> {
+    /// An update with its sequence number
+    Update {
+        /// Server sequence number (usually `None`, see the update's `seq`)
+        seq: Option<u64>,
+        /// The update itself
+        update: T,
+    },
+    /// Messages were dropped or arrived out of order
+    ///
+    /// Only detected from server sequence numbers, so never yielded while
+    /// the server omits `seq`.
+    Gap {
+        /// Sequence number that was expected next
+        expected: u64,
+        /// Sequence number that was received
+        got: u64,
+    },
+}
+
+// =============================================================================
+// Connection Types
+// =============================================================================
+
+/// State of a WebSocket connection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConnectionStatus {
+    /// The connection is open
+    Connected,
+    /// Closed by `shutdown` or by dropping the client
+    Shutdown,
+    /// Closed by the server, with the reason from its close frame (if any)
+    ClosedByServer(Option<String>),
+    /// Closed after a connection error
+    Failed(String),
+}
+
+impl ConnectionStatus {
+    /// Whether the connection is still open.
+    pub fn is_connected(&self) -> bool {
+        matches!(self, ConnectionStatus::Connected)
+    }
+}
+
+// =============================================================================
+// Debug Types
+// =============================================================================
+
+/// Snapshot of a WebSocket client's subscriptions and activity.
+///
+/// Returned by `DflowPredictionWsClient::debug_state`.
+#[derive(Debug, Clone)]
+pub struct WsDebugState {
+    /// Whether the connection is still running
+    pub connected: bool,
+    /// Active subscriptions, oldest first
+    pub subscriptions: Vec<SubscriptionState>,
+    /// Tickers unsubscribed individually, by channel name, whose updates
+    /// are dropped
+    pub dropped_tickers: HashMap<String, Vec<String>>,
+    /// Number of channel messages received on the connection
+    pub messages_received: u64,
+    /// When the last channel message was received
+    pub last_message_at: Option<SystemTime>,
+}
+
+/// State of a single subscription.
+#[derive(Debug, Clone)]
+pub struct SubscriptionState {
+    /// Subscription id, unique per connection
+    pub id: u64,
+    /// Subscribed channel
+    pub channel: Channel,
+    /// Subscribed tickers (all markets on the channel if `None`)
+    pub tickers: Option<Vec<String>>,
+    /// Number of messages delivered to the subscription
+    pub messages: u64,
+    /// When the last message was delivered to the subscription
+    pub last_message_at: Option<SystemTime>,
+}
+
 /// Internal struct for deserializing incoming messages to determine channel.
 #[derive(Debug, Deserialize)]
 pub(crate) struct RawMessage {
     pub channel: String,
+    #[serde(default)]
+    pub market_ticker: Option<String>,
 }
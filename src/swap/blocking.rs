@@ -0,0 +1,216 @@
+//! Blocking client for the DFlow Swap API.
+//!
+//! Only available with the `blocking` feature enabled. Wraps the async
+//! client and drives it on an internal single-threaded Tokio runtime, so no
+//! runtime is needed by the caller. The methods must not be called from
+//! within an async runtime, as blocking there panics.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use dflow_api_client::swap::{GetQuoteParams, blocking::DflowSwapApiClient};
+//!
+//! let client = DflowSwapApiClient::with_default_url("api-key".to_string());
+//!
+//! let quote = client.get_quote(GetQuoteParams::sol_to_usdc(1_000_000_000)).unwrap();
+//! println!("Out amount: {}", quote.out_amount);
+//! ```
+
+use std::sync::Arc;
+
+use tokio::runtime::Runtime;
+
+use super::{DflowSwapApiClient as AsyncClient, Result, types::*};
+use crate::common::{
+    ClientConfig, DflowConfig, RequestOptions, blocking_runtime,
+};
+
+/// Blocking client for the DFlow Swap API.
+///
+/// Mirrors [`AsyncClient`] method for method. Clones share the connection
+/// pool and runtime.
+#[derive(Clone)]
+pub struct DflowSwapApiClient {
+    inner: AsyncClient,
+    runtime: Arc<Runtime>,
+}
+
+impl DflowSwapApiClient {
+    /// Create a new blocking client, see [`AsyncClient::new`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the API key isn't a valid header value or the runtime
+    /// can't be created.
+    pub fn new(base_url: String, api_key: String) -> Self {
+        Self::from_async(AsyncClient::new(base_url, api_key))
+    }
+
+    /// Create a new blocking client, see [`AsyncClient::try_new`].
+    pub fn try_new(base_url: String, api_key: String) -> Result<Self> {
+        Ok(Self::from_async(AsyncClient::try_new(base_url, api_key)?))
+    }
+
+    /// Create a new blocking client with custom HTTP configuration, see
+    /// [`AsyncClient::with_config`].
+    pub fn with_config(
+        base_url: String,
+        api_key: String,
+        config: ClientConfig,
+    ) -> Self {
+        Self::from_async(AsyncClient::with_config(base_url, api_key, config))
+    }
+
+    /// Create a new blocking client with custom HTTP configuration, see
+    /// [`AsyncClient::try_with_config`].
+    pub fn try_with_config(
+        base_url: String,
+        api_key: String,
+        config: ClientConfig,
+    ) -> Result<Self> {
+        Ok(Self::from_async(AsyncClient::try_with_config(
+            base_url, api_key, config,
+        )?))
+    }
+
+    /// Create a new blocking client from shared configuration, see
+    /// [`AsyncClient::with_shared_config`].
+    pub fn with_shared_config(
+        api_key: String,
+        config: &DflowConfig,
+    ) -> Result<Self> {
+        Ok(Self::from_async(AsyncClient::with_shared_config(
+            api_key, config,
+        )?))
+    }
+
+    /// Create a new blocking client with the default base URL.
+    pub fn with_default_url(api_key: String) -> Self {
+        Self::from_async(AsyncClient::with_default_url(api_key))
+    }
+
+    /// Wrap an async client.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the runtime can't be created.
+    pub fn from_async(inner: AsyncClient) -> Self {
+        Self {
+            inner,
+            runtime: Arc::new(blocking_runtime()),
+        }
+    }
+
+    /// See [`AsyncClient::with_debug_requests`].
+    pub fn with_debug_requests(mut self, enabled: bool) -> Self {
+        self.inner = self.inner.with_debug_requests(enabled);
+        self
+    }
+
+    /// The wrapped async client.
+    pub fn as_async(&self) -> &AsyncClient {
+        &self.inner
+    }
+
+    /// See [`AsyncClient::observed_dexes`].
+    pub fn observed_dexes(&self) -> Vec<String> {
+        self.inner.observed_dexes()
+    }
+
+    // =========================================================================
+    // API Endpoints
+    // =========================================================================
+
+    /// Blocking version of [`AsyncClient::warm_up`].
+    pub fn warm_up(&self) -> Result<()> {
+        self.runtime.block_on(self.inner.warm_up())
+    }
+
+    /// Blocking version of [`AsyncClient::get_quote`].
+    pub fn get_quote(&self, params: GetQuoteParams) -> Result<QuoteResponse> {
+        self.runtime.block_on(self.inner.get_quote(params))
+    }
+
+    /// Blocking version of [`AsyncClient::get_quotes`].
+    pub fn get_quotes(
+        &self,
+        params: Vec<GetQuoteParams>,
+    ) -> Vec<Result<QuoteResponse>> {
+        self.runtime.block_on(self.inner.get_quotes(params))
+    }
+
+    /// Blocking version of [`AsyncClient::get_quotes_with_concurrency`].
+    pub fn get_quotes_with_concurrency(
+        &self,
+        params: Vec<GetQuoteParams>,
+        concurrency: usize,
+    ) -> Vec<Result<QuoteResponse>> {
+        self.runtime.block_on(
+            self.inner.get_quotes_with_concurrency(params, concurrency),
+        )
+    }
+
+    /// Blocking version of [`AsyncClient::get_price_from_quote`].
+    pub fn get_price_from_quote(
+        &self,
+        input_mint: &str,
+        output_mint: &str,
+        input_decimals: u8,
+        output_decimals: u8,
+    ) -> Result<f64> {
+        self.runtime.block_on(self.inner.get_price_from_quote(
+            input_mint,
+            output_mint,
+            input_decimals,
+            output_decimals,
+        ))
+    }
+
+    /// Blocking version of [`AsyncClient::create_swap`].
+    pub fn create_swap(&self, request: SwapRequest) -> Result<SwapResponse> {
+        self.runtime.block_on(self.inner.create_swap(request))
+    }
+
+    /// Blocking version of [`AsyncClient::create_swap_with_options`].
+    pub fn create_swap_with_options(
+        &self,
+        request: SwapRequest,
+        options: &RequestOptions,
+    ) -> Result<SwapResponse> {
+        self.runtime
+            .block_on(self.inner.create_swap_with_options(request, options))
+    }
+
+    /// Blocking version of [`AsyncClient::simulate_swap`].
+    pub fn simulate_swap(&self, request: SwapRequest) -> Result<SwapResponse> {
+        self.runtime.block_on(self.inner.simulate_swap(request))
+    }
+
+    /// Blocking version of [`AsyncClient::create_swap_with_refresh`].
+    pub fn create_swap_with_refresh(
+        &self,
+        quote_params: GetQuoteParams,
+        swap_request: SwapRequest,
+    ) -> Result<(SwapResponse, QuoteResponse)> {
+        self.runtime.block_on(
+            self.inner
+                .create_swap_with_refresh(quote_params, swap_request),
+        )
+    }
+
+    /// Blocking version of [`AsyncClient::get_intent`].
+    pub fn get_intent(
+        &self,
+        params: GetIntentParams,
+    ) -> Result<IntentResponse> {
+        self.runtime.block_on(self.inner.get_intent(params))
+    }
+
+    /// Blocking version of [`AsyncClient::submit_intent`].
+    pub fn submit_intent(
+        &self,
+        request: SubmitIntentRequest,
+    ) -> Result<SubmitIntentResponse> {
+        self.runtime.block_on(self.inner.submit_intent(request))
+    }
+}
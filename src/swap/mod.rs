@@ -1,17 +1,68 @@
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod types;
 
-use crate::common::{DflowHttpClient, build_query_string, create_http_client};
+#[cfg(feature = "solana")]
+mod rpc;
+#[cfg(feature = "solana")]
+mod transaction;
+
+#[cfg(feature = "solana")]
+use std::collections::HashMap;
+use std::{
+    collections::BTreeSet,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use crate::common::{
+    ClientConfig, DEFAULT_CONCURRENCY, DflowConfig, DflowHttpClient,
+    RateLimiter, RequestOptions, RetryPolicy, build_query_string,
+    fetch_concurrent, try_create_http_client,
+};
 
 /// Error type for the DFlow Swap API.
 pub type DflowSwapApiError = crate::common::DflowApiError;
 /// Result type for the DFlow Swap API.
 pub type Result<T> = crate::common::Result<T>;
 use reqwest::Client;
+#[cfg(feature = "solana")]
+pub use solana_signer::Signer;
+#[cfg(feature = "solana")]
+pub use solana_transaction::{
+    Address, Signature, versioned::VersionedTransaction,
+};
+#[cfg(feature = "solana")]
+pub use transaction::{LAMPORTS_PER_SIGNATURE, QuoteVerification};
 pub use types::*;
 
 /// Default base URL for the DFlow Swap API
 pub const DEFAULT_BASE_URL: &str = "https://swap-api.dflow.net";
 
+/// USDC mint address
+///
+/// # Example
+///
+/// ```
+/// use dflow_api_client::swap::{USDC_MINT, USDT_MINT, WRAPPED_SOL_MINT};
+///
+/// // Base58-encoded 32-byte public keys are 32 to 44 characters long
+/// for mint in [USDC_MINT, USDT_MINT, WRAPPED_SOL_MINT] {
+///     assert!((32..=44).contains(&mint.len()));
+///     assert!(mint.chars().all(|c| {
+///         c.is_ascii_alphanumeric() && !matches!(c, '0' | 'O' | 'I' | 'l')
+///     }));
+/// }
+/// ```
+pub const USDC_MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+
+/// USDT mint address
+pub const USDT_MINT: &str = "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB";
+
+/// Wrapped SOL mint address, used as the mint for native SOL swaps
+pub const WRAPPED_SOL_MINT: &str =
+    "So11111111111111111111111111111111111111112";
+
 /// Client for interacting with the DFlow Swap API.
 ///
 /// Supports both imperative (quote + swap) and declarative (intent-based) swap flows.
@@ -47,6 +98,11 @@ pub const DEFAULT_BASE_URL: &str = "https://swap-api.dflow.net";
 pub struct DflowSwapApiClient {
     http_client: Client,
     base_url: String,
+    debug_requests: bool,
+    config: ClientConfig,
+    observed_dexes: Arc<Mutex<BTreeSet<String>>>,
+    #[cfg(feature = "solana")]
+    decimals_cache: Arc<Mutex<HashMap<String, u8>>>,
 }
 
 impl DflowHttpClient for DflowSwapApiClient {
@@ -57,6 +113,22 @@ impl DflowHttpClient for DflowSwapApiClient {
     fn base_url(&self) -> &str {
         &self.base_url
     }
+
+    fn debug_requests(&self) -> bool {
+        self.debug_requests
+    }
+
+    fn operation_timeout(&self, operation: &str) -> Option<Duration> {
+        self.config.timeout_for(operation)
+    }
+
+    fn retry_policy(&self) -> Option<&RetryPolicy> {
+        self.config.retry_policy.as_ref()
+    }
+
+    fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.config.rate_limiter.as_ref()
+    }
 }
 
 impl DflowSwapApiClient {
@@ -66,10 +138,134 @@ impl DflowSwapApiClient {
     ///
     /// * `base_url` - Base URL for the API (e.g., "https://swap-api.dflow.net")
     /// * `api_key` - API key for authentication
+    ///
+    /// # Panics
+    ///
+    /// Panics if the API key isn't a valid header value. Use `try_new` to
+    /// handle this as an error.
     pub fn new(base_url: String, api_key: String) -> Self {
+        Self::with_config(base_url, api_key, ClientConfig::default())
+    }
+
+    /// Create a new DFlow Swap API client, returning an error instead of panicking.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - Base URL for the API (e.g., "https://swap-api.dflow.net")
+    /// * `api_key` - API key for authentication
+    ///
+    /// # Returns
+    ///
+    /// The client, or `InvalidParameter` if the API key contains bytes that
+    /// aren't allowed in a header (e.g. a trailing newline).
+    pub fn try_new(base_url: String, api_key: String) -> Result<Self> {
+        Self::try_with_config(base_url, api_key, ClientConfig::default())
+    }
+
+    /// Create a new client with custom HTTP configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - Base URL for the API
+    /// * `api_key` - API key for authentication
+    /// * `config` - HTTP configuration, including per-operation timeouts
+    ///
+    /// # Panics
+    ///
+    /// Panics if the API key isn't a valid header value. Use
+    /// `try_with_config` to handle this as an error.
+    pub fn with_config(
+        base_url: String,
+        api_key: String,
+        config: ClientConfig,
+    ) -> Self {
+        Self::try_with_config(base_url, api_key, config)
+            .expect("Failed to build HTTP client")
+    }
+
+    /// Create a new client with custom HTTP configuration, returning an
+    /// error instead of panicking.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - Base URL for the API
+    /// * `api_key` - API key for authentication
+    /// * `config` - HTTP configuration, including per-operation timeouts
+    pub fn try_with_config(
+        base_url: String,
+        api_key: String,
+        config: ClientConfig,
+    ) -> Result<Self> {
+        Ok(Self {
+            http_client: try_create_http_client(&api_key, &config)?,
+            base_url,
+            debug_requests: false,
+            config,
+            observed_dexes: Arc::default(),
+            #[cfg(feature = "solana")]
+            decimals_cache: Arc::default(),
+        })
+    }
+
+    /// Create a new client from configuration shared with the other
+    /// DFlow clients.
+    ///
+    /// Uses `config.swap_base_url`, or `DEFAULT_BASE_URL` if unset.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - API key for authentication
+    /// * `config` - Shared configuration
+    ///
+    /// # Returns
+    ///
+    /// The client, or `InvalidParameter` if the API key, a header or the
+    /// proxy URL is invalid.
+    pub fn with_shared_config(
+        api_key: String,
+        config: &DflowConfig,
+    ) -> Result<Self> {
+        let base_url = config
+            .swap_base_url
+            .clone()
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+        Ok(
+            Self::try_with_config(base_url, api_key, config.http.clone())?
+                .with_debug_requests(config.debug_requests),
+        )
+    }
+
+    /// Create a new client from a pre-built `reqwest::Client`.
+    ///
+    /// Lets several DFlow clients share one connection pool, proxy and TLS
+    /// configuration. The API key isn't added to requests, so the supplied
+    /// client must already send it in an `x-api-key` default header.
+    /// Settings of [`ClientConfig`] don't apply: timeouts, headers and
+    /// proxies are whatever the supplied client was built with, and failed
+    /// requests aren't retried.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - HTTP client sending the `x-api-key` header
+    /// * `base_url` - Base URL for the API
+    ///
+    /// See [`DflowPredictionApiClient::from_client`][shared] for an example
+    /// sharing one client between both APIs.
+    ///
+    /// [shared]: crate::prediction::DflowPredictionApiClient::from_client
+    pub fn from_client(client: Client, base_url: String) -> Self {
         Self {
-            http_client: create_http_client(&api_key),
+            http_client: client,
             base_url,
+            debug_requests: false,
+            // The supplied client's own timeout applies
+            config: ClientConfig {
+                timeout: None,
+                ..Default::default()
+            },
+            observed_dexes: Arc::default(),
+            #[cfg(feature = "solana")]
+            decimals_cache: Arc::default(),
         }
     }
 
@@ -82,6 +278,54 @@ impl DflowSwapApiClient {
         Self::new(DEFAULT_BASE_URL.to_string(), api_key)
     }
 
+    /// Include a redacted summary of the request body in POST errors.
+    ///
+    /// Useful for diagnosing rejected requests. Only mints, amounts,
+    /// slippage and tickers are included; signatures, public keys,
+    /// transactions and addresses are never part of the summary. Disabled
+    /// by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to include the request summary
+    pub fn with_debug_requests(mut self, enabled: bool) -> Self {
+        self.debug_requests = enabled;
+        self
+    }
+
+    /// Establish a pooled connection to the API ahead of the first request.
+    ///
+    /// Resolves DNS and completes the TLS handshake by issuing a cheap `HEAD`
+    /// request to the base URL, so a latency-sensitive first quote doesn't
+    /// pay for connection setup. Useful at trading startup and in
+    /// short-lived (e.g. serverless) processes. Clones of the client share
+    /// the warmed connection pool.
+    ///
+    /// Any HTTP response, including error statuses, counts as success since
+    /// the connection is established either way.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` once the connection is established, or the transport error
+    /// (DNS, TLS, timeout) for the caller to ignore or act on.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use dflow_api_client::swap::DflowSwapApiClient;
+    ///
+    /// # async fn example() {
+    /// let client = DflowSwapApiClient::with_default_url("api-key".to_string());
+    ///
+    /// // Best effort: the first quote still works if warming fails
+    /// let _ = client.warm_up().await;
+    /// # }
+    /// ```
+    pub async fn warm_up(&self) -> Result<()> {
+        self.http_client.head(&self.base_url).send().await?;
+        Ok(())
+    }
+
     // =========================================================================
     // Imperative Swap API Endpoints
     // =========================================================================
@@ -97,7 +341,9 @@ impl DflowSwapApiClient {
     ///
     /// # Returns
     ///
-    /// Quote response with pricing and route information.
+    /// Quote response with pricing and route information, or
+    /// `InvalidParameter` if the amount isn't a positive integer, a mint
+    /// isn't a valid address or both mints are the same.
     ///
     /// # Example
     ///
@@ -107,12 +353,10 @@ impl DflowSwapApiClient {
     /// # async fn example() {
     /// let client = DflowSwapApiClient::with_default_url("api-key".to_string());
     ///
+    /// // 1 SOL to USDC with 0.5% slippage
     /// let params = GetQuoteParams {
-    ///     input_mint: "So11111111111111111111111111111111111111112".to_string(),
-    ///     output_mint: "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string(),
-    ///     amount: "1000000000".to_string(),
     ///     slippage_bps: Some(50),
-    ///     ..Default::default()
+    ///     ..GetQuoteParams::sol_to_usdc(1_000_000_000)
     /// };
     ///
     /// let quote = client.get_quote(params).await.unwrap();
@@ -122,6 +366,9 @@ impl DflowSwapApiClient {
         &self,
         params: GetQuoteParams,
     ) -> Result<QuoteResponse> {
+        params.validate_amount()?;
+        params.validate_mints()?;
+
         let query = build_query_string(&[
             ("inputMint", Some(params.input_mint)),
             ("outputMint", Some(params.output_mint)),
@@ -131,7 +378,167 @@ impl DflowSwapApiClient {
             ("userPublicKey", params.user_public_key),
         ]);
 
-        self.get(&format!("/quote{}", query)).await
+        let quote: QuoteResponse = self
+            .get_op("get_quote", &format!("/quote{}", query))
+            .await?;
+        self.record_dexes(&quote);
+
+        Ok(quote)
+    }
+
+    /// Get quotes for several swaps concurrently.
+    ///
+    /// Keeps at most `DEFAULT_CONCURRENCY` requests in flight. A failed quote
+    /// doesn't affect the others.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Quote parameters of every swap
+    ///
+    /// # Returns
+    ///
+    /// The result of every quote, in the order of `params`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use dflow_api_client::swap::{
+    ///     DflowSwapApiClient, GetQuoteParams, USDC_MINT, USDT_MINT,
+    ///     WRAPPED_SOL_MINT,
+    /// };
+    ///
+    /// # async fn example() {
+    /// let client = DflowSwapApiClient::with_default_url("api-key".to_string());
+    ///
+    /// let mints = [WRAPPED_SOL_MINT, USDT_MINT];
+    /// let params = mints
+    ///     .iter()
+    ///     .map(|mint| GetQuoteParams::new(*mint, USDC_MINT, 1_000_000))
+    ///     .collect();
+    ///
+    /// for (mint, quote) in mints.iter().zip(client.get_quotes(params).await) {
+    ///     match quote {
+    ///         Ok(quote) => println!("{}: {}", mint, quote.out_amount),
+    ///         Err(e) => println!("{}: {}", mint, e),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub async fn get_quotes(
+        &self,
+        params: Vec<GetQuoteParams>,
+    ) -> Vec<Result<QuoteResponse>> {
+        self.get_quotes_with_concurrency(params, DEFAULT_CONCURRENCY)
+            .await
+    }
+
+    /// Get quotes for several swaps with at most `concurrency` requests in
+    /// flight.
+    ///
+    /// See [`get_quotes`](Self::get_quotes).
+    pub async fn get_quotes_with_concurrency(
+        &self,
+        params: Vec<GetQuoteParams>,
+        concurrency: usize,
+    ) -> Vec<Result<QuoteResponse>> {
+        fetch_concurrent(params, concurrency, |params| self.get_quote(params))
+            .await
+            .into_iter()
+            .map(|(_, result)| result)
+            .collect()
+    }
+
+    /// Get the current price of a token pair from a quote.
+    ///
+    /// The Swap API has no dedicated price endpoint, so this fetches an
+    /// ExactIn quote for one whole input token and divides the amounts. The
+    /// price therefore includes fees and the price impact of that size.
+    /// Quotes don't carry token decimals, so they must be passed in.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_mint` - Mint of the token to price
+    /// * `output_mint` - Mint of the token to price it in
+    /// * `input_decimals` - Decimals of the input token (e.g. 9 for SOL)
+    /// * `output_decimals` - Decimals of the output token (e.g. 6 for USDC)
+    ///
+    /// # Returns
+    ///
+    /// Output tokens per input token, e.g. the SOL price in USDC, or
+    /// `InvalidData` if the quote amounts aren't valid integers.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use dflow_api_client::swap::{DflowSwapApiClient, USDC_MINT, WRAPPED_SOL_MINT};
+    ///
+    /// # async fn example() {
+    /// let client = DflowSwapApiClient::with_default_url("api-key".to_string());
+    ///
+    /// let price = client
+    ///     .get_price_from_quote(WRAPPED_SOL_MINT, USDC_MINT, 9, 6)
+    ///     .await
+    ///     .unwrap();
+    /// println!("1 SOL = {:.2} USDC", price);
+    /// # }
+    /// ```
+    pub async fn get_price_from_quote(
+        &self,
+        input_mint: &str,
+        output_mint: &str,
+        input_decimals: u8,
+        output_decimals: u8,
+    ) -> Result<f64> {
+        let one_token =
+            10u64.checked_pow(input_decimals.into()).ok_or_else(|| {
+                DflowSwapApiError::InvalidParameter(format!(
+                    "input_decimals {} is too large",
+                    input_decimals
+                ))
+            })?;
+        let quote = self
+            .get_quote(GetQuoteParams::new(input_mint, output_mint, one_token))
+            .await?;
+
+        quote
+            .execution_price(input_decimals, output_decimals)
+            .ok_or_else(|| {
+                DflowSwapApiError::InvalidData(format!(
+                    "quote amounts in={:?} out={:?}",
+                    quote.in_amount, quote.out_amount
+                ))
+            })
+    }
+
+    /// Get the DEX labels observed in quote route plans so far.
+    ///
+    /// The Swap API doesn't expose a list of supported venues, so this is
+    /// accumulated from the `label` of every `RoutePlanStep` returned by
+    /// `get_quote` on this client (and its clones). It only contains venues
+    /// that have actually been routed through, so it grows as more quotes are
+    /// fetched.
+    ///
+    /// # Returns
+    ///
+    /// The distinct DEX labels, sorted alphabetically.
+    pub fn observed_dexes(&self) -> Vec<String> {
+        self.observed_dexes
+            .lock()
+            .map(|dexes| dexes.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Record the DEX labels from a quote's route plan.
+    fn record_dexes(&self, quote: &QuoteResponse) {
+        let Some(route_plan) = &quote.route_plan else {
+            return;
+        };
+
+        if let Ok(mut dexes) = self.observed_dexes.lock() {
+            dexes.extend(
+                route_plan.iter().filter_map(|step| step.label.clone()),
+            );
+        }
     }
 
     /// Create a swap transaction from a quote.
@@ -183,7 +590,167 @@ impl DflowSwapApiClient {
         &self,
         request: SwapRequest,
     ) -> Result<SwapResponse> {
-        self.post("/swap", &request).await
+        self.post_op("create_swap", "/swap", &request).await
+    }
+
+    /// Create a swap transaction with per-request options.
+    ///
+    /// Same as [`create_swap`](Self::create_swap), sending the extra headers
+    /// of `options`, e.g. a correlation ID to trace the swap through DFlow's
+    /// logs.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use dflow_api_client::RequestOptions;
+    /// use dflow_api_client::swap::{DflowSwapApiClient, SwapRequest};
+    ///
+    /// # async fn example(
+    /// #     client: DflowSwapApiClient,
+    /// #     swap_request: SwapRequest,
+    /// # ) -> Result<(), Box<dyn std::error::Error>> {
+    /// // `swap_request` built as in the `create_swap` example
+    /// let options = RequestOptions::new().with_header("X-Request-Id", "swap-42");
+    /// let swap = client
+    ///     .create_swap_with_options(swap_request, &options)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_swap_with_options(
+        &self,
+        request: SwapRequest,
+        options: &RequestOptions,
+    ) -> Result<SwapResponse> {
+        self.post_op_with_options("create_swap", "/swap", &request, options)
+            .await
+    }
+
+    /// Create a swap transaction and require its simulation to succeed.
+    ///
+    /// Sets `dynamic_compute_unit_limit`, which makes the API simulate the
+    /// transaction to size its compute budget, and turns a reported
+    /// `simulation_error` into an error. Nothing is submitted, so this can
+    /// check that a swap will go through before asking the user to sign.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - Swap request containing the quote and user's public key
+    ///
+    /// # Returns
+    ///
+    /// The swap response, or `SimulationFailed` with the simulation error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use dflow_api_client::swap::{
+    ///     DflowSwapApiClient, DflowSwapApiError, SwapRequest,
+    /// };
+    ///
+    /// # async fn example(
+    /// #     client: DflowSwapApiClient,
+    /// #     swap_request: SwapRequest,
+    /// # ) -> Result<(), Box<dyn std::error::Error>> {
+    /// // `swap_request` built as in the `create_swap` example
+    /// match client.simulate_swap(swap_request).await {
+    ///     Ok(swap) => println!("Ready to sign: {}", swap.swap_transaction),
+    ///     Err(DflowSwapApiError::SimulationFailed(reason)) => {
+    ///         println!("Swap would fail: {}", reason)
+    ///     }
+    ///     Err(e) => return Err(e.into()),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn simulate_swap(
+        &self,
+        request: SwapRequest,
+    ) -> Result<SwapResponse> {
+        let request = SwapRequest {
+            dynamic_compute_unit_limit: Some(true),
+            ..request
+        };
+
+        let swap = self.create_swap(request).await?;
+        match swap.simulation_error {
+            Some(error) => Err(DflowSwapApiError::SimulationFailed(error)),
+            None => Ok(swap),
+        }
+    }
+
+    /// Fetch a quote and create a swap, refreshing the quote once if stale.
+    ///
+    /// Quotes can go stale in volatile markets, causing `create_swap` to fail
+    /// with [`DflowSwapApiError::StaleQuote`]. In that case a fresh quote is
+    /// fetched and the swap is retried exactly once. If the refreshed quote is
+    /// also stale, the `StaleQuote` error is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `quote_params` - Parameters used to fetch (and refresh) the quote
+    /// * `swap_request` - Swap options; its `quote_response` is replaced with
+    ///   the fetched quote
+    ///
+    /// # Returns
+    ///
+    /// The swap response together with the quote it was created from.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use dflow_api_client::swap::{
+    ///     DflowSwapApiClient, GetQuoteParams, SwapRequest,
+    /// };
+    ///
+    /// # async fn example() {
+    /// let client = DflowSwapApiClient::with_default_url("api-key".to_string());
+    ///
+    /// let quote_params = GetQuoteParams {
+    ///     input_mint: "So11111111111111111111111111111111111111112".to_string(),
+    ///     output_mint: "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string(),
+    ///     amount: "1000000000".to_string(),
+    ///     slippage_bps: Some(50),
+    ///     ..Default::default()
+    /// };
+    /// let swap_request = SwapRequest {
+    ///     user_public_key: "YourWalletPublicKey".to_string(),
+    ///     wrap_and_unwrap_sol: Some(true),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let (swap, quote) = client
+    ///     .create_swap_with_refresh(quote_params, swap_request)
+    ///     .await
+    ///     .unwrap();
+    /// println!("Swapping {} -> {}", quote.in_amount, quote.out_amount);
+    /// println!("Transaction: {}", swap.swap_transaction);
+    /// # }
+    /// ```
+    pub async fn create_swap_with_refresh(
+        &self,
+        quote_params: GetQuoteParams,
+        swap_request: SwapRequest,
+    ) -> Result<(SwapResponse, QuoteResponse)> {
+        let quote = self.get_quote(quote_params.clone()).await?;
+        let request = SwapRequest {
+            quote_response: quote.clone(),
+            ..swap_request.clone()
+        };
+
+        match self.create_swap(request).await {
+            Ok(swap) => Ok((swap, quote)),
+            Err(DflowSwapApiError::StaleQuote(_)) => {
+                let quote = self.get_quote(quote_params).await?;
+                let request = SwapRequest {
+                    quote_response: quote.clone(),
+                    ..swap_request
+                };
+                let swap = self.create_swap(request).await?;
+                Ok((swap, quote))
+            }
+            Err(e) => Err(e),
+        }
     }
 
     // =========================================================================
@@ -201,7 +768,9 @@ impl DflowSwapApiClient {
     ///
     /// # Returns
     ///
-    /// Intent response with quote and intent ID for submission.
+    /// Intent response with quote and intent ID for submission, or
+    /// `InvalidParameter` if a mint isn't a valid address or both mints are
+    /// the same.
     ///
     /// # Example
     ///
@@ -226,6 +795,8 @@ impl DflowSwapApiClient {
         &self,
         params: GetIntentParams,
     ) -> Result<IntentResponse> {
+        validate_mints(&params.input_mint, &params.output_mint)?;
+
         let query = build_query_string(&[
             ("inputMint", Some(params.input_mint)),
             ("outputMint", Some(params.output_mint)),
@@ -234,7 +805,8 @@ impl DflowSwapApiClient {
             ("userPublicKey", params.user_public_key),
         ]);
 
-        self.get(&format!("/intent{}", query)).await
+        self.get_op("get_intent", &format!("/intent{}", query))
+            .await
     }
 
     /// Submit an intent for execution.
@@ -285,6 +857,7 @@ impl DflowSwapApiClient {
         &self,
         request: SubmitIntentRequest,
     ) -> Result<SubmitIntentResponse> {
-        self.post("/submit-intent", &request).await
+        self.post_op("submit_intent", "/submit-intent", &request)
+            .await
     }
 }
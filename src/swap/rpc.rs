@@ -0,0 +1,404 @@
+//! Solana RPC helpers for the Swap client.
+//!
+//! These are only available with the `solana` feature enabled. They talk to a
+//! Solana RPC node directly, not to the DFlow API.
+
+use std::{sync::OnceLock, time::Duration};
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use reqwest::Client;
+use serde_json::{Value, json};
+use solana_signer::Signer;
+
+use super::{
+    DflowSwapApiClient, DflowSwapApiError, Result, SwapResponse,
+    transaction::encode_transaction,
+};
+
+/// SPL Token program, owner of token mints
+const TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+
+/// SPL Token-2022 program, owner of token mints with extensions
+const TOKEN_2022_PROGRAM_ID: &str =
+    "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+
+/// Size of an SPL Token mint account without extensions
+const MINT_LEN: usize = 82;
+
+/// Offset of the `decimals` field in an SPL Token (and Token-2022) mint account
+const MINT_DECIMALS_OFFSET: usize = 44;
+
+/// Offset of the `is_initialized` flag in a mint account
+const MINT_IS_INITIALIZED_OFFSET: usize = 45;
+
+/// Offset of the account type byte of Token-2022 accounts with extensions
+/// (the size of a token account)
+const ACCOUNT_TYPE_OFFSET: usize = 165;
+
+/// Token-2022 account type of mints
+const ACCOUNT_TYPE_MINT: u8 = 1;
+
+/// Time between confirmation polls of a sent transaction
+const CONFIRMATION_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How long to wait for confirmation when the swap has no
+/// `last_valid_block_height` (about the lifetime of a blockhash)
+const CONFIRMATION_TIMEOUT: Duration = Duration::from_secs(90);
+
+/// Timeout of a single RPC request, so a hung node can't stall a poll
+const RPC_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+impl DflowSwapApiClient {
+    /// Fetch the decimals of a token mint from a Solana RPC node.
+    ///
+    /// Useful as a fallback when the API doesn't return `TokenInfo.decimals`.
+    /// This makes a `getAccountInfo` call to the given Solana RPC endpoint,
+    /// not a DFlow call, and the DFlow API key is not sent to it. Since mint
+    /// decimals never change, results are cached for the lifetime of the
+    /// client (shared between clones) and only the first lookup per mint hits
+    /// the RPC node.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Token mint address
+    /// * `rpc_url` - Solana JSON-RPC endpoint URL
+    ///
+    /// # Returns
+    ///
+    /// The number of decimals of the mint. Fails with `InvalidData` if the
+    /// account isn't an initialized SPL Token or Token-2022 mint, and with
+    /// `Rpc` if the node returns a JSON-RPC error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use dflow_api_client::swap::DflowSwapApiClient;
+    ///
+    /// # async fn example() {
+    /// let client = DflowSwapApiClient::with_default_url("api-key".to_string());
+    ///
+    /// let decimals = client
+    ///     .fetch_decimals(
+    ///         "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+    ///         "https://api.mainnet-beta.solana.com",
+    ///     )
+    ///     .await
+    ///     .unwrap();
+    /// assert_eq!(decimals, 6);
+    /// # }
+    /// ```
+    pub async fn fetch_decimals(
+        &self,
+        mint: &str,
+        rpc_url: &str,
+    ) -> Result<u8> {
+        if let Some(decimals) = self
+            .decimals_cache
+            .lock()
+            .ok()
+            .and_then(|cache| cache.get(mint).copied())
+        {
+            return Ok(decimals);
+        }
+
+        let account = rpc_call(
+            rpc_url,
+            "getAccountInfo",
+            json!([mint, { "encoding": "base64" }]),
+        )
+        .await?;
+        let account = &account["value"];
+        if account.is_null() {
+            return Err(DflowSwapApiError::NotFound(format!(
+                "mint account {}",
+                mint
+            )));
+        }
+
+        let not_a_mint = || {
+            DflowSwapApiError::InvalidData(format!(
+                "account {} is not a token mint",
+                mint
+            ))
+        };
+        let owner = account["owner"].as_str().ok_or_else(not_a_mint)?;
+        if owner != TOKEN_PROGRAM_ID && owner != TOKEN_2022_PROGRAM_ID {
+            return Err(not_a_mint());
+        }
+        let data = account
+            .pointer("/data/0")
+            .and_then(Value::as_str)
+            .ok_or_else(not_a_mint)?;
+        let bytes = STANDARD.decode(data).map_err(|e| {
+            DflowSwapApiError::InvalidData(format!("mint account data: {}", e))
+        })?;
+
+        // Token-2022 mints with extensions are tagged after the base layout
+        let is_mint = bytes.len() == MINT_LEN
+            || (owner == TOKEN_2022_PROGRAM_ID
+                && bytes.get(ACCOUNT_TYPE_OFFSET) == Some(&ACCOUNT_TYPE_MINT));
+        if !is_mint {
+            return Err(not_a_mint());
+        }
+        if bytes[MINT_IS_INITIALIZED_OFFSET] != 1 {
+            return Err(DflowSwapApiError::InvalidData(format!(
+                "mint {} is not initialized",
+                mint
+            )));
+        }
+        let decimals = bytes[MINT_DECIMALS_OFFSET];
+
+        if let Ok(mut cache) = self.decimals_cache.lock() {
+            cache.insert(mint.to_string(), decimals);
+        }
+
+        Ok(decimals)
+    }
+
+    /// Sign a swap transaction, send it and wait for confirmation.
+    ///
+    /// Signs with [`SwapResponse::sign_transaction`], sends the transaction
+    /// to the given Solana RPC node (with preflight checks) and polls its
+    /// status until it is confirmed, fails, or the chain passes the swap's
+    /// `last_valid_block_height`. Failed polls are retried until then.
+    /// Without a block height, waiting gives up after 90 seconds. Each RPC
+    /// request times out after 30 seconds, so an unresponsive node counts
+    /// as a failed poll. Only the RPC node is contacted, not the DFlow API.
+    ///
+    /// # Arguments
+    ///
+    /// * `swap` - Swap response from `create_swap`
+    /// * `signer` - Wallet of the swap's `user_public_key`, e.g. a `Keypair`
+    /// * `rpc_url` - Solana JSON-RPC endpoint URL
+    ///
+    /// # Returns
+    ///
+    /// The transaction signature (base58). Fails with `Rpc` if the node
+    /// rejected the transaction, it failed on chain or it expired, and with
+    /// `Unconfirmed` (carrying the signature) if it may have been sent but
+    /// its outcome couldn't be determined. Don't swap again after
+    /// `Unconfirmed` without checking the signature first.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use dflow_api_client::swap::{
+    ///     DflowSwapApiClient, GetQuoteParams, Signer, SwapRequest,
+    /// };
+    ///
+    /// # async fn example(
+    /// #     wallet: impl Signer,
+    /// # ) -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DflowSwapApiClient::with_default_url("api-key".to_string());
+    ///
+    /// let quote = client
+    ///     .get_quote(GetQuoteParams::sol_to_usdc(1_000_000_000))
+    ///     .await?;
+    /// let swap = client
+    ///     .create_swap(SwapRequest {
+    ///         quote_response: quote,
+    ///         user_public_key: wallet.pubkey().to_string(),
+    ///         ..Default::default()
+    ///     })
+    ///     .await?;
+    ///
+    /// let signature = client
+    ///     .execute_swap(&swap, &wallet, "https://api.mainnet-beta.solana.com")
+    ///     .await?;
+    /// println!("Confirmed: {}", signature);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn execute_swap(
+        &self,
+        swap: &SwapResponse,
+        signer: &impl Signer,
+        rpc_url: &str,
+    ) -> Result<String> {
+        let transaction = swap.signed_transaction(signer)?;
+        // The fee payer's signature identifies the transaction
+        let signature = transaction
+            .signatures
+            .first()
+            .map(ToString::to_string)
+            .unwrap_or_default();
+        let unconfirmed = |reason: String| DflowSwapApiError::Unconfirmed {
+            signature: signature.clone(),
+            reason,
+        };
+
+        let sent = rpc_call(
+            rpc_url,
+            "sendTransaction",
+            json!([encode_transaction(&transaction)?, {
+                "encoding": "base64",
+                "preflightCommitment": "confirmed",
+            }]),
+        )
+        .await;
+        match sent {
+            Ok(_) => {}
+            // The node answered, so it didn't accept the transaction
+            Err(error @ DflowSwapApiError::Rpc { .. }) => return Err(error),
+            // The request may have reached the node before failing
+            Err(error) => return Err(unconfirmed(error.to_string())),
+        }
+
+        let started = tokio::time::Instant::now();
+        loop {
+            // Error of the last failed poll, if any
+            let mut last_error = match signature_status(rpc_url, &signature)
+                .await
+            {
+                Ok(SignatureStatus::Confirmed) => return Ok(signature),
+                Ok(SignatureStatus::Failed(error)) => {
+                    return Err(DflowSwapApiError::Rpc {
+                        method: "getSignatureStatuses".to_string(),
+                        message: format!(
+                            "transaction {} failed: {}",
+                            signature, error
+                        ),
+                    });
+                }
+                Ok(SignatureStatus::Pending) => None,
+                Err(error) => {
+                    tracing::debug!(%error, "failed to poll signature status");
+                    Some(error)
+                }
+            };
+
+            let timed_out = started.elapsed() > CONFIRMATION_TIMEOUT;
+            let expired = match swap.last_valid_block_height {
+                Some(last_valid) => match block_height(rpc_url).await {
+                    Ok(height) => height > last_valid,
+                    Err(error) => {
+                        tracing::debug!(%error, "failed to poll block height");
+                        last_error = Some(error);
+                        timed_out
+                    }
+                },
+                None => timed_out,
+            };
+            if expired {
+                return Err(match last_error {
+                    Some(error) => unconfirmed(error.to_string()),
+                    None if swap.last_valid_block_height.is_some() => {
+                        DflowSwapApiError::Rpc {
+                            method: "getSignatureStatuses".to_string(),
+                            message: format!(
+                                "transaction {} expired before confirmation",
+                                signature
+                            ),
+                        }
+                    }
+                    None => unconfirmed(format!(
+                        "not confirmed within {:?}",
+                        CONFIRMATION_TIMEOUT
+                    )),
+                });
+            }
+
+            tokio::time::sleep(CONFIRMATION_POLL_INTERVAL).await;
+        }
+    }
+}
+
+/// Status of a sent transaction.
+enum SignatureStatus {
+    /// Not (yet) confirmed
+    Pending,
+    /// Confirmed or finalized
+    Confirmed,
+    /// Landed and failed, with the transaction error
+    Failed(Value),
+}
+
+/// Look up the status of a transaction by signature.
+async fn signature_status(
+    rpc_url: &str,
+    signature: &str,
+) -> Result<SignatureStatus> {
+    let statuses =
+        rpc_call(rpc_url, "getSignatureStatuses", json!([[signature]])).await?;
+    let status = &statuses["value"][0];
+    if let Some(error) = status.get("err").filter(|e| !e.is_null()) {
+        return Ok(SignatureStatus::Failed(error.clone()));
+    }
+    let confirmation = status["confirmationStatus"].as_str();
+    if matches!(confirmation, Some("confirmed" | "finalized")) {
+        Ok(SignatureStatus::Confirmed)
+    } else {
+        Ok(SignatureStatus::Pending)
+    }
+}
+
+/// Current block height at `confirmed` commitment.
+async fn block_height(rpc_url: &str) -> Result<u64> {
+    let height = rpc_call(
+        rpc_url,
+        "getBlockHeight",
+        json!([{ "commitment": "confirmed" }]),
+    )
+    .await?;
+    height.as_u64().ok_or_else(|| {
+        DflowSwapApiError::InvalidData(format!(
+            "getBlockHeight result: {}",
+            height
+        ))
+    })
+}
+
+/// HTTP client for Solana RPC calls, shared by all swap clients.
+///
+/// Kept separate from the API client so the DFlow API key is never sent to
+/// the RPC node. Requests time out after `RPC_REQUEST_TIMEOUT`; a timed-out
+/// call fails like any other transport error.
+fn rpc_client() -> &'static Client {
+    static CLIENT: OnceLock<Client> = OnceLock::new();
+    CLIENT.get_or_init(|| {
+        Client::builder()
+            .timeout(RPC_REQUEST_TIMEOUT)
+            .build()
+            .expect("Failed to build RPC HTTP client")
+    })
+}
+
+/// Make a JSON-RPC call to a Solana node.
+///
+/// # Returns
+///
+/// The `result` of the call, or an `Rpc` error if the node answered with
+/// an error status or a JSON-RPC error.
+async fn rpc_call(rpc_url: &str, method: &str, params: Value) -> Result<Value> {
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": method,
+        "params": params,
+    });
+
+    let response = rpc_client().post(rpc_url).json(&request).send().await?;
+    let status = response.status();
+    let body = response.text().await?;
+    if !status.is_success() {
+        return Err(DflowSwapApiError::Rpc {
+            method: method.to_string(),
+            message: format!("HTTP {}: {}", status.as_u16(), body),
+        });
+    }
+
+    let mut value: Value = serde_json::from_str(&body)
+        .map_err(|e| DflowSwapApiError::parse_error(method, e, &body))?;
+    if let Some(error) = value.get("error") {
+        let message = error
+            .get("message")
+            .and_then(Value::as_str)
+            .map_or_else(|| error.to_string(), str::to_string);
+        return Err(DflowSwapApiError::Rpc {
+            method: method.to_string(),
+            message,
+        });
+    }
+
+    Ok(value["result"].take())
+}
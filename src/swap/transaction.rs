@@ -0,0 +1,313 @@
+//! Helpers for inspecting the serialized swap transaction.
+//!
+//! These are only available with the `solana` feature enabled.
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use bincode::Options;
+use solana_signer::Signer;
+use solana_transaction::versioned::VersionedTransaction;
+
+use super::{
+    DflowSwapApiError, Result,
+    types::{QuoteResponse, SwapResponse},
+};
+
+/// Base fee charged by the Solana runtime per transaction signature, in lamports
+pub const LAMPORTS_PER_SIGNATURE: u64 = 5000;
+
+/// Outcome of [`SwapResponse::verify_against_quote`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QuoteVerification {
+    /// Both quote mints are static accounts of the transaction
+    Verified,
+    /// Some quote mints aren't static accounts of the transaction, but it
+    /// loads accounts from address lookup tables that may contain them
+    Unverifiable {
+        /// Quote mints not found among the static accounts
+        missing_mints: Vec<String>,
+    },
+}
+
+impl SwapResponse {
+    /// Number of signatures required by the swap transaction.
+    ///
+    /// # Returns
+    ///
+    /// The signature count, or `None` if the transaction can't be decoded.
+    pub fn signature_count(&self) -> Option<u64> {
+        let transaction = self.decode_transaction().ok()?;
+        Some(transaction.signatures.len() as u64)
+    }
+
+    /// Estimate the total network fee of the swap transaction before signing.
+    ///
+    /// Sums the base fee (5000 lamports per signature) and the priority fee
+    /// reported by the API.
+    ///
+    /// # Returns
+    ///
+    /// The estimated fee in lamports, or `None` if the priority fee is missing
+    /// or the transaction can't be decoded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dflow_api_client::swap::SwapResponse;
+    ///
+    /// let response = SwapResponse {
+    ///     // A legacy transaction with a single required signature
+    ///     swap_transaction: "AQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABAAECAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQECAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAQEBAAA=".to_string(),
+    ///     priority_fee_lamports: Some(10_000),
+    ///     last_valid_block_height: None,
+    ///     priority_fee_type: None,
+    ///     compute_unit_limit: None,
+    ///     dynamic_slippage_report: None,
+    ///     simulation_error: None,
+    /// };
+    ///
+    /// assert_eq!(response.signature_count(), Some(1));
+    /// assert_eq!(response.estimated_total_fee_lamports(), Some(15_000));
+    /// ```
+    pub fn estimated_total_fee_lamports(&self) -> Option<u64> {
+        let priority_fee = self.priority_fee_lamports?;
+        let base_fee = self.signature_count()? * LAMPORTS_PER_SIGNATURE;
+        Some(base_fee + priority_fee)
+    }
+}
+
+impl SwapResponse {
+    /// Decode the base64-encoded swap transaction.
+    ///
+    /// Handles both legacy and versioned (v0) transactions. The decoded
+    /// transaction is unsigned and ready to be signed by the user's wallet.
+    ///
+    /// # Returns
+    ///
+    /// The transaction, or `InvalidData` if it isn't valid base64 or the
+    /// bytes aren't a serialized transaction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use base64::{Engine, engine::general_purpose::STANDARD};
+    /// use dflow_api_client::{DflowApiError, swap::SwapResponse};
+    ///
+    /// let response = |swap_transaction: String| SwapResponse {
+    ///     swap_transaction,
+    ///     priority_fee_lamports: None,
+    ///     last_valid_block_height: None,
+    ///     priority_fee_type: None,
+    ///     compute_unit_limit: None,
+    ///     dynamic_slippage_report: None,
+    ///     simulation_error: None,
+    /// };
+    ///
+    /// // A legacy transaction with a single required signature
+    /// let legacy = "AQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABAAECAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQECAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAQEBAAA=";
+    /// let tx = response(legacy.to_string()).decode_transaction().unwrap();
+    /// assert_eq!(tx.signatures.len(), 1);
+    /// assert!(tx.message.address_table_lookups().is_none());
+    ///
+    /// // The same message as a v0 transaction: version prefix after the
+    /// // signatures and an empty address table lookup list at the end
+    /// let mut bytes = STANDARD.decode(legacy).unwrap();
+    /// bytes.insert(65, 0x80);
+    /// bytes.push(0);
+    /// let tx = response(STANDARD.encode(bytes)).decode_transaction().unwrap();
+    /// assert_eq!(tx.message.address_table_lookups(), Some(&[][..]));
+    ///
+    /// assert!(matches!(
+    ///     response("AQID".to_string()).decode_transaction(),
+    ///     Err(DflowApiError::InvalidData(_))
+    /// ));
+    /// ```
+    pub fn decode_transaction(&self) -> Result<VersionedTransaction> {
+        let bytes = STANDARD.decode(&self.swap_transaction).map_err(|e| {
+            DflowSwapApiError::InvalidData(format!("swap transaction: {}", e))
+        })?;
+
+        // Same encoding the Solana runtime uses for transactions
+        bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .reject_trailing_bytes()
+            .deserialize(&bytes)
+            .map_err(|e| {
+                DflowSwapApiError::InvalidData(format!(
+                    "swap transaction: {}",
+                    e
+                ))
+            })
+    }
+
+    /// Check that the swap transaction is consistent with a quote.
+    ///
+    /// A safety check before signing: decodes the transaction and verifies
+    /// that the quote's input and output mints are among the accounts it
+    /// references, guarding against a backend returning a transaction for
+    /// different tokens.
+    ///
+    /// This is a static check with known limits. Accounts loaded from address
+    /// lookup tables are not visible without an RPC call, so a mint missing
+    /// from the static accounts of a transaction that uses lookup tables
+    /// can't be verified either way. Amounts (including the minimum output)
+    /// are encoded in program-specific instruction data and are not verified.
+    ///
+    /// # Arguments
+    ///
+    /// * `quote` - The quote the swap was created from
+    ///
+    /// # Returns
+    ///
+    /// [`QuoteVerification::Verified`] if both mints are referenced,
+    /// [`QuoteVerification::Unverifiable`] if a mint may be loaded from a
+    /// lookup table, otherwise a `VerificationFailed` error naming the
+    /// missing mint.
+    pub fn verify_against_quote(
+        &self,
+        quote: &QuoteResponse,
+    ) -> Result<QuoteVerification> {
+        let transaction = self.decode_transaction()?;
+        let account_keys = transaction.message.static_account_keys();
+
+        let missing_mints: Vec<(&str, &String)> =
+            [("input", &quote.input_mint), ("output", &quote.output_mint)]
+                .into_iter()
+                .filter(|(_, mint)| {
+                    !account_keys.iter().any(|key| key.to_string() == **mint)
+                })
+                .collect();
+
+        let uses_lookup_tables = transaction
+            .message
+            .address_table_lookups()
+            .is_some_and(|lookups| !lookups.is_empty());
+
+        match missing_mints.first() {
+            None => Ok(QuoteVerification::Verified),
+            Some(_) if uses_lookup_tables => {
+                Ok(QuoteVerification::Unverifiable {
+                    missing_mints: missing_mints
+                        .into_iter()
+                        .map(|(_, mint)| mint.clone())
+                        .collect(),
+                })
+            }
+            Some((side, mint)) => {
+                Err(DflowSwapApiError::VerificationFailed(format!(
+                    "{} mint {} is not referenced by the transaction",
+                    side, mint
+                )))
+            }
+        }
+    }
+
+    /// Sign the swap transaction.
+    ///
+    /// Signs the transaction message and stores the signature in the
+    /// signer's slot, keeping any other signatures. Works for legacy and
+    /// versioned transactions alike.
+    ///
+    /// # Arguments
+    ///
+    /// * `signer` - Wallet signing the transaction, e.g. a `Keypair`
+    ///
+    /// # Returns
+    ///
+    /// The signed transaction, base64-encoded for `sendTransaction`, or
+    /// `InvalidParameter` if the signer isn't a required signer of the
+    /// transaction or fails to sign.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use base64::{Engine, engine::general_purpose::STANDARD};
+    /// use dflow_api_client::swap::{Address, Signature, Signer, SwapResponse};
+    /// use solana_signer::SignerError;
+    ///
+    /// struct Wallet;
+    ///
+    /// impl Signer for Wallet {
+    ///     fn try_pubkey(&self) -> Result<Address, SignerError> {
+    ///         Ok(Address::from([1; 32]))
+    ///     }
+    ///
+    ///     fn try_sign_message(
+    ///         &self,
+    ///         _message: &[u8],
+    ///     ) -> Result<Signature, SignerError> {
+    ///         Ok(Signature::from([7; 64]))
+    ///     }
+    ///
+    ///     fn is_interactive(&self) -> bool {
+    ///         false
+    ///     }
+    /// }
+    ///
+    /// let response = SwapResponse {
+    ///     // A legacy transaction signed by the account [1; 32]
+    ///     swap_transaction: "AQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABAAECAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQECAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAQEBAAA=".to_string(),
+    ///     last_valid_block_height: None,
+    ///     priority_fee_type: None,
+    ///     priority_fee_lamports: None,
+    ///     compute_unit_limit: None,
+    ///     dynamic_slippage_report: None,
+    ///     simulation_error: None,
+    /// };
+    ///
+    /// let signed = response.sign_transaction(&Wallet).unwrap();
+    /// let bytes = STANDARD.decode(signed).unwrap();
+    /// assert_eq!(bytes[1..65], [7; 64]);
+    /// ```
+    pub fn sign_transaction(&self, signer: &impl Signer) -> Result<String> {
+        encode_transaction(&self.signed_transaction(signer)?)
+    }
+
+    /// Decode the swap transaction and sign it with `signer`.
+    pub(crate) fn signed_transaction(
+        &self,
+        signer: &impl Signer,
+    ) -> Result<VersionedTransaction> {
+        let mut transaction = self.decode_transaction()?;
+        let pubkey = signer.try_pubkey().map_err(|e| {
+            DflowSwapApiError::InvalidParameter(format!("signer: {}", e))
+        })?;
+        let required_signatures =
+            usize::from(transaction.message.header().num_required_signatures);
+        let index = transaction
+            .message
+            .static_account_keys()
+            .iter()
+            .take(required_signatures)
+            .position(|key| *key == pubkey)
+            .ok_or_else(|| {
+                DflowSwapApiError::InvalidParameter(format!(
+                    "{} is not a signer of the swap transaction",
+                    pubkey
+                ))
+            })?;
+
+        let signature = signer
+            .try_sign_message(&transaction.message.serialize())
+            .map_err(|e| {
+                DflowSwapApiError::InvalidParameter(format!("signer: {}", e))
+            })?;
+        let slot = transaction.signatures.get_mut(index).ok_or_else(|| {
+            DflowSwapApiError::InvalidData(
+                "swap transaction: missing signature slot".to_string(),
+            )
+        })?;
+        *slot = signature;
+
+        Ok(transaction)
+    }
+}
+
+/// Serialize a transaction and encode it as base64.
+pub(crate) fn encode_transaction(
+    transaction: &VersionedTransaction,
+) -> Result<String> {
+    let bytes = bincode::serialize(transaction).map_err(|e| {
+        DflowSwapApiError::InvalidData(format!("swap transaction: {}", e))
+    })?;
+    Ok(STANDARD.encode(bytes))
+}
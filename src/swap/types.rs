@@ -1,10 +1,17 @@
 use serde::{Deserialize, Serialize};
 
+use super::{Result, USDC_MINT, WRAPPED_SOL_MINT};
+use crate::common::DflowApiError;
+
 // =============================================================================
 // Common Types
 // =============================================================================
 
 /// Token information in swap context
+///
+/// The Swap API has no token list or token metadata endpoint, so nothing in
+/// this crate returns it. With the `solana` feature, decimals can be looked
+/// up from a Solana RPC node with `DflowSwapApiClient::fetch_decimals`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TokenInfo {
@@ -48,22 +55,317 @@ pub struct SwapFee {
 // =============================================================================
 
 /// Query parameters for GET /quote endpoint
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct GetQuoteParams {
     /// Input token mint address (required)
     pub input_mint: String,
     /// Output token mint address (required)
     pub output_mint: String,
     /// Amount to swap in smallest unit (e.g., lamports) (required)
+    ///
+    /// The amount of the input token to sell for ExactIn quotes, or of the
+    /// output token to buy for ExactOut quotes (`exact_in: Some(false)`).
     pub amount: String,
     /// Slippage tolerance in basis points (e.g., 50 = 0.5%)
     pub slippage_bps: Option<u32>,
     /// Whether the amount is for input (true) or output (false)
+    ///
+    /// Defaults to ExactIn if `None`. Prefer `QuoteBuilder` to make the
+    /// meaning of `amount` explicit.
     pub exact_in: Option<bool>,
     /// User's wallet public key (optional, for priority fees)
     pub user_public_key: Option<String>,
 }
 
+impl GetQuoteParams {
+    /// Create quote parameters for swapping `amount` of one token for
+    /// another.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_mint` - Mint of the token to sell
+    /// * `output_mint` - Mint of the token to buy
+    /// * `amount` - Amount to swap in the smallest unit of the input token
+    pub fn new(
+        input_mint: impl Into<String>,
+        output_mint: impl Into<String>,
+        amount: u64,
+    ) -> Self {
+        Self {
+            input_mint: input_mint.into(),
+            output_mint: output_mint.into(),
+            amount: amount.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Start building quote parameters for selling exactly `amount`.
+    ///
+    /// Shorthand for [`QuoteBuilder::exact_in`]; use
+    /// [`QuoteBuilder::exact_out`] to buy an exact amount instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dflow_api_client::swap::{GetQuoteParams, USDC_MINT, WRAPPED_SOL_MINT};
+    ///
+    /// let params = GetQuoteParams::builder(WRAPPED_SOL_MINT, USDC_MINT, 1_000)
+    ///     .slippage_bps(50)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(params.exact_in, Some(true));
+    /// ```
+    pub fn builder(
+        input_mint: impl Into<String>,
+        output_mint: impl Into<String>,
+        amount: u64,
+    ) -> QuoteBuilder {
+        QuoteBuilder::exact_in(input_mint, output_mint, amount)
+    }
+
+    /// Create quote parameters for swapping SOL to USDC.
+    ///
+    /// # Arguments
+    ///
+    /// * `lamports` - Amount of SOL to sell, in lamports
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dflow_api_client::swap::{GetQuoteParams, USDC_MINT, WRAPPED_SOL_MINT};
+    ///
+    /// let params = GetQuoteParams::sol_to_usdc(1_000_000_000);
+    /// assert_eq!(params.input_mint, WRAPPED_SOL_MINT);
+    /// assert_eq!(params.output_mint, USDC_MINT);
+    /// assert_eq!(params.amount, "1000000000");
+    /// ```
+    pub fn sol_to_usdc(lamports: u64) -> Self {
+        Self::new(WRAPPED_SOL_MINT, USDC_MINT, lamports)
+    }
+
+    /// Create quote parameters for swapping USDC to SOL.
+    ///
+    /// # Arguments
+    ///
+    /// * `micro_usdc` - Amount of USDC to sell, in micro-USDC (6 decimals)
+    pub fn usdc_to_sol(micro_usdc: u64) -> Self {
+        Self::new(USDC_MINT, WRAPPED_SOL_MINT, micro_usdc)
+    }
+
+    /// Check that `amount` is a positive integer.
+    pub(crate) fn validate_amount(&self) -> Result<()> {
+        match self.amount.parse::<u64>() {
+            Ok(amount) if amount > 0 => Ok(()),
+            _ => Err(DflowApiError::InvalidParameter(format!(
+                "amount must be a positive integer in the token's smallest \
+                 unit, got {:?}",
+                self.amount
+            ))),
+        }
+    }
+
+    /// Check that both mints look like valid addresses and differ.
+    pub(crate) fn validate_mints(&self) -> Result<()> {
+        validate_mints(&self.input_mint, &self.output_mint)
+    }
+}
+
+/// Check that both mints of a swap look like valid addresses and differ.
+///
+/// Catches typos before they reach the server. Without the `solana` feature
+/// only the length and the base58 alphabet are checked; with it the address
+/// must decode to 32 bytes.
+pub(crate) fn validate_mints(
+    input_mint: &str,
+    output_mint: &str,
+) -> Result<()> {
+    validate_mint("input_mint", input_mint)?;
+    validate_mint("output_mint", output_mint)?;
+    if input_mint == output_mint {
+        return Err(DflowApiError::InvalidParameter(format!(
+            "input_mint and output_mint are the same ({})",
+            input_mint
+        )));
+    }
+    Ok(())
+}
+
+/// Check that `mint` looks like a valid address.
+fn validate_mint(name: &str, mint: &str) -> Result<()> {
+    let invalid = |reason: &str| {
+        Err(DflowApiError::InvalidParameter(format!(
+            "{} is not a valid mint address ({}): {:?}",
+            name, reason, mint
+        )))
+    };
+
+    if !(32..=44).contains(&mint.len()) {
+        return invalid("expected 32 to 44 characters");
+    }
+    // Base58 leaves out 0, O, I and l
+    if !mint.chars().all(|c| {
+        c.is_ascii_alphanumeric() && !matches!(c, '0' | 'O' | 'I' | 'l')
+    }) {
+        return invalid("not base58");
+    }
+    #[cfg(feature = "solana")]
+    if bs58::decode(mint).into_vec().map(|key| key.len()) != Ok(32) {
+        return invalid("not 32 bytes");
+    }
+    Ok(())
+}
+
+/// Convert a smallest-unit amount string to whole tokens.
+fn ui_amount(amount: &str, decimals: u8) -> Option<f64> {
+    let amount = amount.parse::<u64>().ok()?;
+    Some(amount as f64 / 10f64.powi(decimals.into()))
+}
+
+/// Builder for quote parameters that makes the swap mode explicit.
+///
+/// ExactIn quotes sell a fixed amount of the input token, ExactOut quotes
+/// buy a fixed amount of the output token. Building fails with
+/// `InvalidParameter` if the amount isn't a positive integer, so a
+/// malformed amount is caught before any request is made.
+///
+/// # Example
+///
+/// ```
+/// use dflow_api_client::{
+///     DflowApiError,
+///     swap::{QuoteBuilder, USDC_MINT, WRAPPED_SOL_MINT},
+/// };
+///
+/// // Buy exactly 10 USDC with SOL
+/// let params = QuoteBuilder::exact_out(WRAPPED_SOL_MINT, USDC_MINT, "10000000")
+///     .slippage_bps(50)
+///     .build()
+///     .unwrap();
+/// assert_eq!(params.exact_in, Some(false));
+/// assert_eq!(params.amount, "10000000");
+///
+/// // Sell exactly 1 SOL
+/// let params = QuoteBuilder::exact_in(WRAPPED_SOL_MINT, USDC_MINT, 1_000_000_000)
+///     .build()
+///     .unwrap();
+/// assert_eq!(params.exact_in, Some(true));
+///
+/// for amount in ["", "1.5", "-3", "0", "ten"] {
+///     assert!(matches!(
+///         QuoteBuilder::exact_in(WRAPPED_SOL_MINT, USDC_MINT, amount).build(),
+///         Err(DflowApiError::InvalidParameter(_))
+///     ));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct QuoteBuilder {
+    params: GetQuoteParams,
+}
+
+impl QuoteBuilder {
+    /// Quote selling exactly `amount` of the input token.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_mint` - Mint of the token to sell
+    /// * `output_mint` - Mint of the token to buy
+    /// * `amount` - Amount to sell, in the input token's smallest unit
+    pub fn exact_in(
+        input_mint: impl Into<String>,
+        output_mint: impl Into<String>,
+        amount: impl ToString,
+    ) -> Self {
+        Self::with_mode(input_mint, output_mint, amount, true)
+    }
+
+    /// Quote buying exactly `amount` of the output token.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_mint` - Mint of the token to sell
+    /// * `output_mint` - Mint of the token to buy
+    /// * `amount` - Amount to buy, in the output token's smallest unit
+    pub fn exact_out(
+        input_mint: impl Into<String>,
+        output_mint: impl Into<String>,
+        amount: impl ToString,
+    ) -> Self {
+        Self::with_mode(input_mint, output_mint, amount, false)
+    }
+
+    fn with_mode(
+        input_mint: impl Into<String>,
+        output_mint: impl Into<String>,
+        amount: impl ToString,
+        exact_in: bool,
+    ) -> Self {
+        Self {
+            params: GetQuoteParams {
+                input_mint: input_mint.into(),
+                output_mint: output_mint.into(),
+                amount: amount.to_string(),
+                exact_in: Some(exact_in),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Set the slippage tolerance in basis points (e.g., 50 = 0.5%).
+    pub fn slippage_bps(mut self, slippage_bps: u32) -> Self {
+        self.params.slippage_bps = Some(slippage_bps);
+        self
+    }
+
+    /// Set the user's wallet public key.
+    pub fn user_public_key(
+        mut self,
+        user_public_key: impl Into<String>,
+    ) -> Self {
+        self.params.user_public_key = Some(user_public_key.into());
+        self
+    }
+
+    /// Build the quote parameters.
+    ///
+    /// # Returns
+    ///
+    /// The parameters, or `InvalidParameter` if the amount isn't a positive
+    /// integer, a mint isn't a valid address or both mints are the same.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dflow_api_client::{
+    ///     DflowApiError,
+    ///     swap::{QuoteBuilder, USDC_MINT, WRAPPED_SOL_MINT},
+    /// };
+    ///
+    /// // "0" isn't part of the base58 alphabet
+    /// let typo = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".replace('1', "0");
+    /// let result = QuoteBuilder::exact_in(typo, USDC_MINT, 1_000).build();
+    /// assert!(matches!(
+    ///     result,
+    ///     Err(DflowApiError::InvalidParameter(message))
+    ///         if message.starts_with("input_mint")
+    /// ));
+    ///
+    /// let result = QuoteBuilder::exact_in(USDC_MINT, USDC_MINT, 1_000).build();
+    /// assert!(matches!(result, Err(DflowApiError::InvalidParameter(_))));
+    ///
+    /// assert!(
+    ///     QuoteBuilder::exact_in(WRAPPED_SOL_MINT, USDC_MINT, 1_000)
+    ///         .build()
+    ///         .is_ok()
+    /// );
+    /// ```
+    pub fn build(self) -> Result<GetQuoteParams> {
+        self.params.validate_amount()?;
+        self.params.validate_mints()?;
+        Ok(self.params)
+    }
+}
+
 /// Quote response from GET /quote endpoint
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
@@ -99,6 +401,259 @@ pub struct QuoteResponse {
     pub time_taken: Option<f64>,
 }
 
+impl QuoteResponse {
+    /// Output tokens received per input token, in whole tokens.
+    ///
+    /// Works for ExactIn and ExactOut quotes alike, since both report the
+    /// input and output amounts. The quote doesn't include token decimals,
+    /// so they have to be passed in.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_decimals` - Decimals of the input token
+    /// * `output_decimals` - Decimals of the output token
+    ///
+    /// # Returns
+    ///
+    /// The price, or `None` if an amount isn't an integer or the input
+    /// amount is 0. An output amount of 0 gives a price of 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dflow_api_client::swap::QuoteResponse;
+    ///
+    /// // 1 SOL (9 decimals) for 150 USDC (6 decimals)
+    /// let quote = QuoteResponse {
+    ///     in_amount: "1000000000".to_string(),
+    ///     out_amount: "150000000".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(quote.execution_price(9, 6), Some(150.0));
+    /// ```
+    pub fn execution_price(
+        &self,
+        input_decimals: u8,
+        output_decimals: u8,
+    ) -> Option<f64> {
+        let input = self.in_amount_ui(input_decimals)?;
+        let output = self.out_amount_ui(output_decimals)?;
+        if input == 0.0 {
+            return None;
+        }
+        Some(output / input)
+    }
+
+    /// Input amount in whole tokens, or `None` if it isn't an integer.
+    pub fn in_amount_ui(&self, decimals: u8) -> Option<f64> {
+        ui_amount(&self.in_amount, decimals)
+    }
+
+    /// Output amount in whole tokens, or `None` if it isn't an integer.
+    pub fn out_amount_ui(&self, decimals: u8) -> Option<f64> {
+        ui_amount(&self.out_amount, decimals)
+    }
+
+    /// Price impact in percent (e.g. `1.0` for 1%).
+    ///
+    /// See [`price_impact_fraction`](Self::price_impact_fraction) for the
+    /// accepted formats.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dflow_api_client::swap::QuoteResponse;
+    ///
+    /// let quote = QuoteResponse {
+    ///     price_impact_pct: Some("0.0125".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(quote.price_impact_percent(), Some(1.25));
+    /// ```
+    pub fn price_impact_percent(&self) -> Option<f64> {
+        self.price_impact_fraction()
+            .map(|fraction| fraction * 100.0)
+    }
+
+    /// Price impact of the quote as a fraction (e.g. `0.01` for 1%).
+    ///
+    /// Handles plain decimals and scientific notation (e.g. `"1.2e-5"`), which
+    /// the API reports as a fraction, as well as percentage strings with a
+    /// trailing `%` (e.g. `"0.5%"`), which are divided by 100.
+    ///
+    /// # Returns
+    ///
+    /// The price impact fraction, or `None` if it is missing or unparseable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dflow_api_client::swap::QuoteResponse;
+    ///
+    /// let quote = |pct: &str| QuoteResponse {
+    ///     price_impact_pct: Some(pct.to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(quote("0.0025").price_impact_fraction(), Some(0.0025));
+    /// assert_eq!(quote("0.5%").price_impact_fraction(), Some(0.005));
+    /// ```
+    pub fn price_impact_fraction(&self) -> Option<f64> {
+        let raw = self.price_impact_pct.as_deref()?.trim();
+
+        let value = match raw.strip_suffix('%') {
+            Some(percent) => percent.trim().parse::<f64>().ok()? / 100.0,
+            None => raw.parse::<f64>().ok()?,
+        };
+
+        value.is_finite().then_some(value)
+    }
+
+    /// Summarize how risky the quote looks before swapping.
+    ///
+    /// Checks the price impact, slippage tolerance and number of route steps
+    /// against the thresholds of `policy`. The overall level is the highest
+    /// level reached by any check, and every check above `Low` adds a reason.
+    /// A missing or unparseable price impact is treated as `Medium`.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - Thresholds for each risk level
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dflow_api_client::swap::{QuoteResponse, RiskLevel, RiskPolicy};
+    ///
+    /// let clean = QuoteResponse {
+    ///     price_impact_pct: Some("0.001".to_string()),
+    ///     slippage_bps: Some(50),
+    ///     ..Default::default()
+    /// };
+    /// let risk = clean.risk_assessment(&RiskPolicy::default());
+    /// assert_eq!(risk.level, RiskLevel::Low);
+    /// assert!(risk.reasons.is_empty());
+    ///
+    /// let high_impact = QuoteResponse {
+    ///     price_impact_pct: Some("0.12".to_string()),
+    ///     slippage_bps: Some(50),
+    ///     ..Default::default()
+    /// };
+    /// let risk = high_impact.risk_assessment(&RiskPolicy::default());
+    /// assert_eq!(risk.level, RiskLevel::High);
+    /// assert_eq!(risk.reasons.len(), 1);
+    /// ```
+    pub fn risk_assessment(&self, policy: &RiskPolicy) -> QuoteRisk {
+        let mut risk = QuoteRisk {
+            level: RiskLevel::Low,
+            reasons: Vec::new(),
+        };
+
+        match self.price_impact_fraction() {
+            Some(impact) => {
+                let level = if impact >= policy.high_price_impact {
+                    RiskLevel::High
+                } else if impact >= policy.medium_price_impact {
+                    RiskLevel::Medium
+                } else {
+                    RiskLevel::Low
+                };
+                risk.flag(
+                    level,
+                    format!("price impact is {:.2}%", impact * 100.0),
+                );
+            }
+            None => risk
+                .flag(RiskLevel::Medium, "price impact is unknown".to_string()),
+        }
+
+        if let Some(slippage_bps) = self.slippage_bps {
+            let level = if slippage_bps >= policy.high_slippage_bps {
+                RiskLevel::High
+            } else if slippage_bps >= policy.medium_slippage_bps {
+                RiskLevel::Medium
+            } else {
+                RiskLevel::Low
+            };
+            risk.flag(
+                level,
+                format!("slippage tolerance is {} bps", slippage_bps),
+            );
+        }
+
+        let route_steps = self.route_plan.as_ref().map_or(0, Vec::len);
+        let level = if route_steps >= policy.high_route_steps {
+            RiskLevel::High
+        } else if route_steps >= policy.medium_route_steps {
+            RiskLevel::Medium
+        } else {
+            RiskLevel::Low
+        };
+        risk.flag(level, format!("route has {} steps", route_steps));
+
+        risk
+    }
+}
+
+/// Risk level of a quote.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RiskLevel {
+    Low,
+    Medium,
+    High,
+}
+
+/// Risk summary of a quote, see [`QuoteResponse::risk_assessment`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuoteRisk {
+    /// Highest risk level reached by any check
+    pub level: RiskLevel,
+    /// Explanations for every check above `Low`
+    pub reasons: Vec<String>,
+}
+
+impl QuoteRisk {
+    /// Record the outcome of a check, keeping the reason if it isn't `Low`.
+    fn flag(&mut self, level: RiskLevel, reason: String) {
+        if level > RiskLevel::Low {
+            self.level = self.level.max(level);
+            self.reasons.push(reason);
+        }
+    }
+}
+
+/// Thresholds used by [`QuoteResponse::risk_assessment`].
+///
+/// Each value is the lower bound (inclusive) of its risk level.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RiskPolicy {
+    /// Price impact fraction considered medium risk (default 1%)
+    pub medium_price_impact: f64,
+    /// Price impact fraction considered high risk (default 5%)
+    pub high_price_impact: f64,
+    /// Slippage tolerance considered medium risk (default 100 bps)
+    pub medium_slippage_bps: u32,
+    /// Slippage tolerance considered high risk (default 500 bps)
+    pub high_slippage_bps: u32,
+    /// Number of route steps considered medium risk (default 3)
+    pub medium_route_steps: usize,
+    /// Number of route steps considered high risk (default 5)
+    pub high_route_steps: usize,
+}
+
+impl Default for RiskPolicy {
+    fn default() -> Self {
+        Self {
+            medium_price_impact: 0.01,
+            high_price_impact: 0.05,
+            medium_slippage_bps: 100,
+            high_slippage_bps: 500,
+            medium_route_steps: 3,
+            high_route_steps: 5,
+        }
+    }
+}
+
 /// A step in the route plan
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -143,9 +698,92 @@ pub struct SwapRequest {
     /// Skip user accounts RPC calls
     #[serde(default)]
     pub skip_user_accounts_rpc_calls: Option<bool>,
-    /// Priority fee configuration (in lamports or "auto")
+    /// Priority fee configuration (in lamports, "auto" or an auto
+    /// multiplier)
     #[serde(default)]
-    pub priority_fee: Option<serde_json::Value>,
+    pub priority_fee: Option<PriorityFee>,
+}
+
+/// Priority fee setting for a swap transaction.
+///
+/// Serializes to the JSON the Swap API accepts for `priorityFee`: the
+/// string `"auto"`, a number of lamports, or `{"autoMultiplier": n}` to
+/// scale the automatic fee.
+///
+/// # Example
+///
+/// ```
+/// use dflow_api_client::swap::PriorityFee;
+/// use serde_json::json;
+///
+/// assert_eq!(serde_json::to_value(PriorityFee::Auto).unwrap(), json!("auto"));
+/// assert_eq!(
+///     serde_json::to_value(PriorityFee::Lamports(50_000)).unwrap(),
+///     json!(50_000)
+/// );
+/// assert_eq!(
+///     serde_json::to_value(PriorityFee::AutoMultiplier(2.0)).unwrap(),
+///     json!({ "autoMultiplier": 2.0 })
+/// );
+///
+/// // Round trip
+/// for fee in [
+///     PriorityFee::Auto,
+///     PriorityFee::Lamports(50_000),
+///     PriorityFee::AutoMultiplier(1.5),
+/// ] {
+///     let value = serde_json::to_value(&fee).unwrap();
+///     assert_eq!(serde_json::from_value::<PriorityFee>(value).unwrap(), fee);
+/// }
+///
+/// // Other shapes are rejected
+/// assert!(serde_json::from_value::<PriorityFee>(json!("fast")).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "serde_json::Value", into = "serde_json::Value")]
+pub enum PriorityFee {
+    /// Let the API pick the fee from recent network conditions
+    Auto,
+    /// A fixed priority fee in lamports
+    Lamports(u64),
+    /// The automatic fee scaled by a multiplier
+    AutoMultiplier(f64),
+}
+
+impl TryFrom<serde_json::Value> for PriorityFee {
+    type Error = String;
+
+    fn try_from(
+        value: serde_json::Value,
+    ) -> std::result::Result<Self, Self::Error> {
+        if value == "auto" {
+            return Ok(PriorityFee::Auto);
+        }
+        if let Some(lamports) = value.as_u64() {
+            return Ok(PriorityFee::Lamports(lamports));
+        }
+        if let Some(multiplier) = value
+            .as_object()
+            .filter(|object| object.len() == 1)
+            .and_then(|object| object.get("autoMultiplier"))
+            .and_then(serde_json::Value::as_f64)
+        {
+            return Ok(PriorityFee::AutoMultiplier(multiplier));
+        }
+        Err(format!("invalid priority fee: {}", value))
+    }
+}
+
+impl From<PriorityFee> for serde_json::Value {
+    fn from(fee: PriorityFee) -> Self {
+        match fee {
+            PriorityFee::Auto => "auto".into(),
+            PriorityFee::Lamports(lamports) => lamports.into(),
+            PriorityFee::AutoMultiplier(multiplier) => {
+                serde_json::json!({ "autoMultiplier": multiplier })
+            }
+        }
+    }
 }
 
 /// Response from POST /swap endpoint
@@ -179,7 +817,8 @@ pub struct SwapResponse {
 // =============================================================================
 
 /// Query parameters for GET /intent endpoint
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct GetIntentParams {
     /// Input token mint address (required)
     pub input_mint: String,
@@ -221,6 +860,66 @@ pub struct IntentResponse {
     pub metadata: Option<serde_json::Value>,
 }
 
+impl IntentResponse {
+    /// Typed view of the `metadata` field.
+    ///
+    /// # Returns
+    ///
+    /// The parsed metadata, or `None` if it is absent, doesn't match the
+    /// expected shape or has none of the known fields. The raw value remains
+    /// available in `metadata`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dflow_api_client::swap::IntentResponse;
+    ///
+    /// let intent: IntentResponse = serde_json::from_str(
+    ///     r#"{
+    ///         "inputMint": "So11111111111111111111111111111111111111112",
+    ///         "outputMint": "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+    ///         "inAmount": "1000000000",
+    ///         "outAmount": "150000000",
+    ///         "intentId": "intent-1",
+    ///         "metadata": { "solverCount": 3, "routeHints": ["Orca"] }
+    ///     }"#,
+    /// )
+    /// .unwrap();
+    ///
+    /// let metadata = intent.metadata_typed().unwrap();
+    /// assert_eq!(metadata.solver_count, Some(3));
+    /// assert_eq!(metadata.estimated_fill_time_ms, None);
+    /// ```
+    pub fn metadata_typed(&self) -> Option<IntentMetadata> {
+        let metadata: IntentMetadata =
+            serde_json::from_value(self.metadata.clone()?).ok()?;
+        let known = metadata.solver_count.is_some()
+            || metadata.estimated_fill_time_ms.is_some()
+            || metadata.route_hints.is_some();
+        known.then_some(metadata)
+    }
+}
+
+/// Known fields of the intent `metadata` object.
+///
+/// All fields are optional since the metadata contents vary between intents.
+/// The fields are unverified: they come from the request that added this
+/// type, not from captured API responses, and the Swap API doesn't document
+/// the `metadata` object. Check the raw `metadata` if they stay `None`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntentMetadata {
+    /// Number of solvers competing for the intent
+    #[serde(default)]
+    pub solver_count: Option<u32>,
+    /// Estimated time until the intent is filled, in milliseconds
+    #[serde(default)]
+    pub estimated_fill_time_ms: Option<u64>,
+    /// Venues solvers are expected to route through
+    #[serde(default)]
+    pub route_hints: Option<Vec<String>>,
+}
+
 /// Request body for POST /submit-intent endpoint
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
@@ -267,6 +966,132 @@ pub struct SubmitIntentResponse {
     pub details: Option<String>,
 }
 
+impl SubmitIntentResponse {
+    /// The submission status as an `IntentStatus`.
+    ///
+    /// # Returns
+    ///
+    /// The parsed status (case-insensitive), or `None` if the API reported
+    /// a status this client doesn't know. The raw string stays available
+    /// in `status`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dflow_api_client::swap::{IntentStatus, SubmitIntentResponse};
+    ///
+    /// let response = |status: &str| SubmitIntentResponse {
+    ///     status: status.to_string(),
+    ///     intent_id: "intent-1".to_string(),
+    ///     transaction_signature: None,
+    ///     expected_out_amount: None,
+    ///     estimated_completion_time: None,
+    ///     details: None,
+    /// };
+    ///
+    /// assert_eq!(response("pending").intent_status(), Some(IntentStatus::Pending));
+    /// assert!(response("expired").intent_status().unwrap().is_terminal());
+    /// ```
+    pub fn intent_status(&self) -> Option<IntentStatus> {
+        self.status.parse().ok()
+    }
+
+    /// Why the intent failed, parsed from `details`.
+    ///
+    /// `details` may be plain text or a JSON object with a `code`, `reason`,
+    /// `error` or `message` field. The text is classified by keywords; anything
+    /// unrecognized becomes `Other` with the raw details. The raw string stays
+    /// available in `details`.
+    ///
+    /// # Returns
+    ///
+    /// The failure reason if the status is `failed` or `expired`, otherwise
+    /// `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dflow_api_client::swap::{FailureReason, SubmitIntentResponse};
+    ///
+    /// let response = |status: &str, details: &str| SubmitIntentResponse {
+    ///     status: status.to_string(),
+    ///     intent_id: "intent-1".to_string(),
+    ///     transaction_signature: None,
+    ///     expected_out_amount: None,
+    ///     estimated_completion_time: None,
+    ///     details: Some(details.to_string()),
+    /// };
+    ///
+    /// assert_eq!(
+    ///     response("failed", "Slippage tolerance exceeded").failure_reason(),
+    ///     Some(FailureReason::Slippage)
+    /// );
+    /// assert_eq!(
+    ///     response("failed", r#"{"code": "NO_SOLVER_AVAILABLE"}"#)
+    ///         .failure_reason(),
+    ///     Some(FailureReason::NoSolver)
+    /// );
+    /// ```
+    pub fn failure_reason(&self) -> Option<FailureReason> {
+        let status = self.status.to_lowercase();
+        if status == IntentStatus::Expired.as_str() {
+            return Some(FailureReason::Expired);
+        }
+        if status != IntentStatus::Failed.as_str() {
+            return None;
+        }
+
+        let details = self.details.clone().unwrap_or_default();
+        Some(FailureReason::from_details(&details))
+    }
+}
+
+/// Known reasons for a failed intent, see
+/// [`SubmitIntentResponse::failure_reason`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FailureReason {
+    /// The price moved beyond the slippage tolerance
+    Slippage,
+    /// No solver was available to fill the intent
+    NoSolver,
+    /// The intent expired before it was filled
+    Expired,
+    /// Any other failure, with the raw details
+    Other(String),
+}
+
+/// Phrases reporting that no solver could fill an intent, matched after
+/// lowercasing and replacing `_` and `-` with spaces
+const NO_SOLVER_PHRASES: [&str; 2] = ["no solver", "no route"];
+
+impl FailureReason {
+    /// Classify a failure details string.
+    fn from_details(details: &str) -> Self {
+        let text = serde_json::from_str::<serde_json::Value>(details)
+            .ok()
+            .and_then(|value| {
+                ["code", "reason", "error", "message"]
+                    .iter()
+                    .filter_map(|key| value.get(key)?.as_str())
+                    .map(str::to_string)
+                    .reduce(|a, b| format!("{} {}", a, b))
+            })
+            .unwrap_or_else(|| details.to_string())
+            .to_lowercase()
+            .replace(['_', '-'], " ");
+
+        if text.contains("slippage") {
+            FailureReason::Slippage
+        } else if NO_SOLVER_PHRASES.iter().any(|phrase| text.contains(phrase)) {
+            FailureReason::NoSolver
+        } else if text.contains("expired") || text.contains("expiry") {
+            FailureReason::Expired
+        } else {
+            FailureReason::Other(details.to_string())
+        }
+    }
+}
+
 /// Intent status for tracking submitted intents
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -279,6 +1104,27 @@ pub enum IntentStatus {
 }
 
 impl IntentStatus {
+    /// Every intent status.
+    pub const ALL: [IntentStatus; 5] = [
+        IntentStatus::Pending,
+        IntentStatus::Executing,
+        IntentStatus::Completed,
+        IntentStatus::Failed,
+        IntentStatus::Expired,
+    ];
+
+    /// Whether the intent has finished (completed, failed or expired) and
+    /// its status won't change anymore.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            IntentStatus::Completed
+                | IntentStatus::Failed
+                | IntentStatus::Expired
+        )
+    }
+
+    /// Status string as sent by the API, e.g. `"pending"`.
     pub fn as_str(&self) -> &'static str {
         match self {
             IntentStatus::Pending => "pending",
@@ -289,3 +1135,152 @@ impl IntentStatus {
         }
     }
 }
+
+impl std::str::FromStr for IntentStatus {
+    type Err = DflowApiError;
+
+    /// Parse a status string, ignoring case.
+    ///
+    /// Fails with `InvalidData` for a status that isn't one of
+    /// [`IntentStatus::ALL`].
+    fn from_str(status: &str) -> std::result::Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|candidate| status.eq_ignore_ascii_case(candidate.as_str()))
+            .ok_or_else(|| {
+                DflowApiError::InvalidData(format!(
+                    "unknown intent status: {}",
+                    status
+                ))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quote(in_amount: &str, out_amount: &str) -> QuoteResponse {
+        QuoteResponse {
+            in_amount: in_amount.to_string(),
+            out_amount: out_amount.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn submitted(status: &str, details: &str) -> SubmitIntentResponse {
+        SubmitIntentResponse {
+            status: status.to_string(),
+            intent_id: "intent-1".to_string(),
+            transaction_signature: None,
+            expected_out_amount: None,
+            estimated_completion_time: None,
+            details: Some(details.to_string()),
+        }
+    }
+
+    #[test]
+    fn execution_price_edge_cases() {
+        let sol_usdc = quote("1000000000", "150000000");
+        assert_eq!(sol_usdc.in_amount_ui(9), Some(1.0));
+        assert_eq!(sol_usdc.out_amount_ui(6), Some(150.0));
+
+        let zero = quote("1000000000", "0");
+
+        assert_eq!(zero.execution_price(9, 6), Some(0.0));
+        assert_eq!(QuoteResponse::default().execution_price(9, 6), None);
+    }
+
+    #[test]
+    fn price_impact_formats() {
+        let impact = |pct: &str| {
+            QuoteResponse {
+                price_impact_pct: Some(pct.to_string()),
+                ..Default::default()
+            }
+            .price_impact_fraction()
+        };
+
+        assert_eq!(impact("1.2e-5"), Some(0.000012));
+        assert_eq!(impact(" 0.5 % "), Some(0.005));
+        assert_eq!(impact("n/a"), None);
+        assert_eq!(impact("inf"), None);
+    }
+
+    #[test]
+    fn intent_status_is_case_insensitive() {
+        assert_eq!(
+            submitted("Completed", "").intent_status(),
+            Some(IntentStatus::Completed)
+        );
+        assert_eq!(submitted("queued", "").intent_status(), None);
+
+        assert_eq!(
+            "EXPIRED".parse::<IntentStatus>().ok(),
+            Some(IntentStatus::Expired)
+        );
+        assert!(matches!(
+            "queued".parse::<IntentStatus>(),
+            Err(DflowApiError::InvalidData(_))
+        ));
+    }
+
+    #[test]
+    fn failure_reasons() {
+        assert_eq!(
+            submitted("failed", "Slippage tolerance exceeded").failure_reason(),
+            Some(FailureReason::Slippage)
+        );
+        assert_eq!(
+            submitted("failed", "intent expired before fill").failure_reason(),
+            Some(FailureReason::Expired)
+        );
+        assert_eq!(
+            submitted("expired", "").failure_reason(),
+            Some(FailureReason::Expired)
+        );
+        assert_eq!(
+            submitted("failed", "insufficient funds").failure_reason(),
+            Some(FailureReason::Other("insufficient funds".to_string()))
+        );
+        assert_eq!(
+            submitted("failed", "no route found").failure_reason(),
+            Some(FailureReason::NoSolver)
+        );
+        // Mentioning a solver isn't enough
+        let details = r#"{"reason": "solver timeout", "message": "retry"}"#;
+        assert_eq!(
+            submitted("failed", details).failure_reason(),
+            Some(FailureReason::Other(details.to_string()))
+        );
+        assert_eq!(submitted("pending", "queued").failure_reason(), None);
+    }
+
+    #[test]
+    fn metadata_without_known_fields_is_none() {
+        let intent = |metadata: serde_json::Value| IntentResponse {
+            input_mint: String::new(),
+            output_mint: String::new(),
+            in_amount: String::new(),
+            out_amount: String::new(),
+            intent_id: String::new(),
+            expires_at: None,
+            price: None,
+            swap_mode: None,
+            metadata: Some(metadata),
+        };
+
+        let typed = intent(serde_json::json!({ "solverCount": 2 }))
+            .metadata_typed()
+            .unwrap();
+        assert_eq!(typed.solver_count, Some(2));
+
+        assert!(intent(serde_json::json!({})).metadata_typed().is_none());
+        assert!(
+            intent(serde_json::json!({ "venue": "Orca" }))
+                .metadata_typed()
+                .is_none()
+        );
+        assert!(intent(serde_json::json!("n/a")).metadata_typed().is_none());
+    }
+}
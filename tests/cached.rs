@@ -0,0 +1,34 @@
+mod common;
+
+use std::time::Duration;
+
+use dflow_api_client::prediction::{
+    DflowPredictionApiClient,
+    cached::{CacheKey, CachedPredictionClient},
+};
+
+use common::{json_response, serve_once};
+
+#[tokio::test]
+async fn series_are_served_from_the_cache_until_invalidated() {
+    // Answers a single request, later requests fail
+    let url = serve_once(|_| {
+        json_response(
+            r#"{"ticker":"KXBTC","title":"Bitcoin","category":"Crypto"}"#,
+        )
+    });
+
+    let client = CachedPredictionClient::new(
+        DflowPredictionApiClient::new(url, "api-key".to_string()),
+        Duration::from_secs(60),
+    );
+
+    let series = client.get_series_by_ticker("KXBTC").await.unwrap();
+    assert_eq!(series.title, "Bitcoin");
+
+    let series = client.get_series_by_ticker("KXBTC").await.unwrap();
+    assert_eq!(series.category, "Crypto");
+
+    client.invalidate(&CacheKey::Series("KXBTC".to_string()));
+    assert!(client.get_series_by_ticker("KXBTC").await.is_err());
+}
@@ -0,0 +1,40 @@
+//! Mock HTTP server shared by the integration tests.
+
+use std::{
+    io::{Read, Write},
+    net::TcpListener,
+    thread,
+};
+
+/// Serve a single request on a random local port, then stop listening.
+///
+/// `respond` gets the raw request and returns the raw response bytes.
+///
+/// # Returns
+///
+/// The base URL of the server.
+pub fn serve_once(
+    respond: impl FnOnce(&str) -> Vec<u8> + Send + 'static,
+) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut request = [0; 4096];
+        let n = stream.read(&mut request).unwrap();
+        let response = respond(&String::from_utf8_lossy(&request[..n]));
+        stream.write_all(&response).unwrap();
+    });
+    url
+}
+
+/// A `200 OK` response with a JSON body.
+pub fn json_response(body: &str) -> Vec<u8> {
+    format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\
+         Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+        body.len(),
+        body
+    )
+    .into_bytes()
+}
@@ -0,0 +1,87 @@
+mod common;
+
+use std::collections::HashMap;
+
+use dflow_api_client::{
+    DflowApiError, DflowHttpClient, RequestOptions,
+    prediction::DflowPredictionApiClient,
+};
+
+use common::{json_response, serve_once};
+
+/// Minimal client talking to a mock server.
+struct Client(reqwest::Client, String);
+
+impl DflowHttpClient for Client {
+    fn http_client(&self) -> &reqwest::Client {
+        &self.0
+    }
+
+    fn base_url(&self) -> &str {
+        &self.1
+    }
+}
+
+#[tokio::test]
+async fn truncated_body_is_incomplete_response() {
+    // Declares 100 bytes but sends 8 before closing
+    let url = serve_once(|_| {
+        b"HTTP/1.1 200 OK\r\nContent-Length: 100\r\n\r\n{\"events".to_vec()
+    });
+
+    let client = Client(reqwest::Client::new(), url);
+    let result = client.get::<serde_json::Value>("/").await;
+    assert!(matches!(
+        result,
+        Err(DflowApiError::IncompleteResponse {
+            expected: 100,
+            got: 8
+        })
+    ));
+}
+
+#[tokio::test]
+async fn request_options_add_headers() {
+    // Echoes whether the request carried the header
+    let url = serve_once(|request| {
+        let request = request.to_lowercase();
+        json_response(&request.contains("x-request-id: swap-42").to_string())
+    });
+
+    let client = Client(reqwest::Client::new(), url);
+    let options = RequestOptions::new().with_header("X-Request-Id", "swap-42");
+    let seen: bool = client
+        .get_op_with_options("echo", "/", &options)
+        .await
+        .unwrap();
+    assert!(seen);
+}
+
+#[tokio::test]
+async fn refused_connection_is_retryable() {
+    // Nothing listens on port 1
+    let error = reqwest::get("http://127.0.0.1:1").await.unwrap_err();
+    assert!(DflowApiError::from(error).is_retryable());
+}
+
+#[tokio::test]
+async fn holdings_outside_the_market_are_invalid() {
+    // The market lists no outcome mints
+    let url = serve_once(|_| {
+        json_response(
+            r#"{"ticker":"T","title":"","subtitle":"","eventTicker":"E",
+                "marketType":"binary","status":"active","result":"",
+                "canCloseEarly":false,"openTime":0,"closeTime":0,
+                "expirationTime":0,"volume":0,"openInterest":0,
+                "rulesPrimary":"","yesSubTitle":"","noSubTitle":"",
+                "accounts":{},"noBid":"0.40"}"#,
+        )
+    });
+
+    let client = DflowPredictionApiClient::new(url, "api-key".to_string());
+    let holdings = HashMap::from([("Mint".to_string(), 10)]);
+    assert!(matches!(
+        client.value_holdings(&holdings).await,
+        Err(DflowApiError::InvalidData(_))
+    ));
+}
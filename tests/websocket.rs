@@ -0,0 +1,184 @@
+#![cfg(feature = "websocket")]
+
+mod common;
+
+use std::time::Duration;
+
+use common::{json_response, serve_once};
+use dflow_api_client::prediction::{
+    DflowPredictionApiClient,
+    websocket::{
+        Channel, ConnectionStatus, DflowPredictionWsClient, SubscriptionScope,
+        WsConfig,
+    },
+};
+use futures_util::{SinkExt, StreamExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_tungstenite::{
+    WebSocketStream,
+    tungstenite::{
+        Message,
+        protocol::frame::{CloseFrame, coding::CloseCode},
+    },
+};
+
+/// Accept a single WebSocket connection and hand it to `handle`.
+///
+/// # Returns
+///
+/// The URL of the server.
+async fn serve<F, Fut>(handle: F) -> String
+where
+    F: FnOnce(WebSocketStream<TcpStream>) -> Fut + Send + 'static,
+    Fut: Future<Output = ()> + Send,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+        handle(ws).await;
+    });
+    url
+}
+
+/// Read messages until the client goes away.
+async fn drain(ws: &mut WebSocketStream<TcpStream>) {
+    while ws.next().await.is_some() {}
+}
+
+#[tokio::test]
+async fn missing_pongs_fail_the_connection() {
+    // Accepts the connection, then never answers
+    let url = serve(|_ws| std::future::pending()).await;
+
+    let config = WsConfig {
+        ping_interval: Duration::from_millis(50),
+        pong_timeout: Duration::from_millis(120),
+        ..Default::default()
+    };
+    let client =
+        DflowPredictionWsClient::connect_with_config(&url, &[], config)
+            .await
+            .unwrap();
+
+    let mut status = client.status();
+    status
+        .wait_for(|status| !status.is_connected())
+        .await
+        .unwrap();
+    assert!(matches!(*status.borrow(), ConnectionStatus::Failed(_)));
+}
+
+#[tokio::test]
+async fn ticker_subscriptions_get_their_own_updates() {
+    let url = serve(|mut ws| async move {
+        // Wait for both subscriptions before sending updates
+        for _ in 0..2 {
+            ws.next().await.unwrap().unwrap();
+        }
+        for ticker in ["A", "B"] {
+            let update = format!(
+                r#"{{"channel":"prices","type":"ticker","market_ticker":"{}"}}"#,
+                ticker
+            );
+            ws.send(Message::Text(update)).await.unwrap();
+        }
+        drain(&mut ws).await;
+    })
+    .await;
+
+    let client = DflowPredictionWsClient::connect_with_url(&url)
+        .await
+        .unwrap();
+    let (mut a, _unsub_a) = client
+        .prices_subscribe_tickers(vec!["A".to_string()])
+        .await
+        .unwrap();
+    let (mut b, _unsub_b) = client
+        .prices_subscribe_tickers(vec!["B".to_string()])
+        .await
+        .unwrap();
+
+    assert_eq!(a.next().await.unwrap().market_ticker, "A");
+    assert_eq!(b.next().await.unwrap().market_ticker, "B");
+}
+
+#[tokio::test]
+async fn raw_subscriptions_keep_unknown_fields() {
+    let url = serve(|mut ws| async move {
+        ws.next().await.unwrap().unwrap();
+        let update = r#"{"channel":"prices","type":"ticker","market_ticker":"M","new_field":1}"#;
+        ws.send(Message::Text(update.into())).await.unwrap();
+        drain(&mut ws).await;
+    })
+    .await;
+
+    let client = DflowPredictionWsClient::connect_with_url(&url)
+        .await
+        .unwrap();
+    let (mut messages, _unsub) = client
+        .subscribe_raw(
+            Channel::Prices,
+            SubscriptionScope::Tickers(vec!["M".to_string()]),
+        )
+        .await
+        .unwrap();
+
+    let message = messages.next().await.unwrap();
+    assert_eq!(message["new_field"], 1);
+}
+
+#[tokio::test]
+async fn status_reports_the_close_reason() {
+    let url = serve(|mut ws| async move {
+        ws.next().await.unwrap().unwrap();
+        let frame = CloseFrame {
+            code: CloseCode::Away,
+            reason: "maintenance".into(),
+        };
+        ws.send(Message::Close(Some(frame))).await.unwrap();
+        drain(&mut ws).await;
+    })
+    .await;
+
+    let client = DflowPredictionWsClient::connect_with_url(&url)
+        .await
+        .unwrap();
+    let (mut prices, _unsub) = client.prices_subscribe_all().await.unwrap();
+    while prices.next().await.is_some() {}
+
+    assert_eq!(
+        *client.status().borrow(),
+        ConnectionStatus::ClosedByServer(Some("maintenance".to_string()))
+    );
+}
+
+#[tokio::test]
+async fn updates_during_the_snapshot_are_returned_separately() {
+    let url = serve(|mut ws| async move {
+        ws.next().await.unwrap().unwrap();
+        let update = r#"{"channel":"orderbook","type":"orderbook","market_ticker":"M","yes_bids":{"0.40":1}}"#;
+        ws.send(Message::Text(update.into())).await.unwrap();
+        drain(&mut ws).await;
+    })
+    .await;
+    // Answers only after the update was sent
+    let rest_url = serve_once(|_| {
+        std::thread::sleep(Duration::from_millis(200));
+        json_response(r#"{"ticker":"M"}"#)
+    });
+
+    let client = DflowPredictionWsClient::connect_with_url(&url)
+        .await
+        .unwrap();
+    let rest = DflowPredictionApiClient::new(rest_url, "api-key".to_string());
+    let (snapshot, buffered, _updates, _unsub) = client
+        .orderbook_subscribe_with_snapshot(&rest, "M".to_string())
+        .await
+        .unwrap();
+
+    assert_eq!(snapshot.ticker, "M");
+    assert_eq!(buffered.len(), 1);
+    assert_eq!(buffered[0].yes_bids["0.40"], 1);
+}